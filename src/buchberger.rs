@@ -1,19 +1,252 @@
-use crate::{Field, Polynomial};
+use crate::{Field, Monomial, Polynomial};
+use std::cmp::max;
 use std::fmt::{Debug, Display};
 
+/// Order in which critical pairs are reduced.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum SelectionStrategy {
+    /// Smallest lcm of the leading monomials in the ring's order.
+    Normal,
+    /// Smallest total degree of the lcm, ties broken as in `Normal`.
+    Degree,
+    /// Smallest sugar degree, ties broken as in `Normal`. The sugar of a
+    /// polynomial is the degree it would have if the input were homogenized.
+    #[default]
+    Sugar,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct GroebnerConfig {
+    pub strategy: SelectionStrategy,
+}
+
+/// Critical pair of basis elements, identified by their index in the basis.
+pub(crate) struct Pair<'lt, F: Field> {
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) lcm: Monomial<'lt, F>,
+    pub(crate) sugar: u64,
+}
+
 pub fn groebner<F: Field + Debug + Display>(polys: &mut Vec<Polynomial<F>>) {
-    let mut changed = true;
-    while changed {
-        changed = false;
-        'outer: for i in 0..polys.len() {
-            for j in 0..i {
-                let s = polys[i].s_polynomial(&polys[j]) / &*polys;
-                if !s.is_zero() {
-                    polys.push(s);
-                    changed = true;
-                    break 'outer;
-                }
-            }
+    groebner_with(polys, &GroebnerConfig::default());
+}
+
+/// Completes `polys` into a Gröbner basis, processing critical pairs as
+/// described by `config`.
+pub fn groebner_with<F: Field + Debug + Display>(
+    polys: &mut Vec<Polynomial<F>>,
+    config: &GroebnerConfig,
+) {
+    polys.retain(|p| !p.is_zero());
+    complete(polys, config, None);
+}
+
+/// Same as `groebner_with`, also tracking how the basis is obtained from the
+/// input: returns the cofactor matrix whose row `k` holds the polynomials
+/// `c[k][i]` such that `polys[k] = Σ c[k][i] * input[i]`, `input` being the
+/// generators given in `polys` on entry.
+pub fn groebner_with_cofactors<'lt, F: Field + Debug + Display>(
+    polys: &mut Vec<Polynomial<'lt, F>>,
+    config: &GroebnerConfig,
+) -> Vec<Vec<Polynomial<'lt, F>>> {
+    let count = polys.len();
+    let mut cofactors = Vec::new();
+    let mut generators = Vec::new();
+    for (i, p) in polys.drain(..).enumerate() {
+        if p.is_zero() {
+            continue;
+        }
+        let row = (0..count)
+            .map(|k| {
+                let c = if k == i { F::one() } else { F::zero() };
+                Polynomial::new(p.poly_ring(), Vec::new()) + c
+            })
+            .collect();
+        cofactors.push(row);
+        generators.push(p);
+    }
+    *polys = generators;
+    complete(polys, config, Some(&mut cofactors));
+    cofactors
+}
+
+/// Buchberger's algorithm on nonzero `polys`, updating the cofactor matrix
+/// along the way when one is given.
+fn complete<'lt, F: Field + Debug + Display>(
+    polys: &mut Vec<Polynomial<'lt, F>>,
+    config: &GroebnerConfig,
+    mut cofactors: Option<&mut Vec<Vec<Polynomial<'lt, F>>>>,
+) {
+    let mut sugars: Vec<u64> = polys.iter().map(|p| p.degree()).collect();
+    let mut active = Vec::new();
+    let mut pairs = Vec::new();
+    for h in 0..polys.len() {
+        update(polys, &sugars, &mut active, &mut pairs, h);
+    }
+    while let Some(pair) = select(&mut pairs, config.strategy) {
+        let s = polys[pair.i].s_polynomial(&polys[pair.j]);
+        let (q, s, sugar) = reduce(s, pair.sugar, polys, &sugars);
+        if s.is_zero() {
+            continue;
+        }
+        if let Some(cofactors) = cofactors.as_mut() {
+            // s = (lcm / lt_i) * g_i - (lcm / lt_j) * g_j - Σ q_k * g_k
+            let multiplier = |k: usize| {
+                let term = (F::one() / polys[k].lc(), &pair.lcm / &polys[k].lm());
+                Polynomial::new(s.poly_ring(), vec![term])
+            };
+            let (mi, mj) = (multiplier(pair.i), multiplier(pair.j));
+            let row = (0..cofactors[pair.i].len())
+                .map(|c| {
+                    let mut res = mi.clone() * cofactors[pair.i][c].clone()
+                        - mj.clone() * cofactors[pair.j][c].clone();
+                    for (k, q) in q.iter().enumerate() {
+                        if !q.is_zero() {
+                            res = res - q.clone() * cofactors[k][c].clone();
+                        }
+                    }
+                    res
+                })
+                .collect();
+            cofactors.push(row);
+        }
+        polys.push(s);
+        sugars.push(sugar);
+        update(polys, &sugars, &mut active, &mut pairs, polys.len() - 1);
+    }
+}
+
+/// Fully reduces `p` of sugar `sugar` by `polys`, returning the quotients,
+/// the remainder and its sugar.
+fn reduce<'lt, F: Field + Debug + Display>(
+    p: Polynomial<'lt, F>,
+    sugar: u64,
+    polys: &[Polynomial<'lt, F>],
+    sugars: &[u64],
+) -> (Vec<Polynomial<'lt, F>>, Polynomial<'lt, F>, u64) {
+    let (q, r) = p.divide_by(polys);
+    let sugar = q
+        .iter()
+        .zip(sugars)
+        .filter(|(q, _)| !q.is_zero())
+        .map(|(q, s)| q.degree() + s)
+        .fold(sugar, max);
+    (q, r, sugar)
+}
+
+/// Computes the reduced Gröbner basis of the ideal generated by `polys`: the
+/// unique basis whose elements are monic, whose leading monomials do not
+/// divide each other and whose other terms are not divisible by any leading
+/// monomial. Elements are sorted by increasing leading monomial.
+pub fn reduced_groebner<F: Field + Debug + Display>(polys: &mut Vec<Polynomial<F>>) {
+    groebner(polys);
+    reduce_basis(polys);
+}
+
+/// Turns a Gröbner basis into the reduced Gröbner basis of the same ideal.
+pub(crate) fn reduce_basis<F: Field + Debug + Display>(polys: &mut Vec<Polynomial<F>>) {
+    polys.retain(|p| !p.is_zero());
+    polys.sort_by_key(|p| p.lm());
+    let mut minimal: Vec<Polynomial<F>> = Vec::new();
+    for p in polys.drain(..) {
+        if !minimal.iter().any(|g| p.lm().is_divisible(&g.lm())) {
+            let lc = p.lc();
+            minimal.push(p / lc);
+        }
+    }
+    for k in 0..minimal.len() {
+        let others: Vec<Polynomial<F>> = minimal
+            .iter()
+            .enumerate()
+            .filter(|(l, _)| *l != k)
+            .map(|(_, g)| g.clone())
+            .collect();
+        if !others.is_empty() {
+            minimal[k] = minimal[k].clone() / &others;
+        }
+    }
+    *polys = minimal;
+}
+
+/// Removes and returns the next pair to reduce according to `strategy`.
+fn select<'lt, F: Field + Debug + Display>(
+    pairs: &mut Vec<Pair<'lt, F>>,
+    strategy: SelectionStrategy,
+) -> Option<Pair<'lt, F>> {
+    let key = |pair: &Pair<'lt, F>| match strategy {
+        SelectionStrategy::Normal => 0,
+        SelectionStrategy::Degree => pair.lcm.degree(),
+        SelectionStrategy::Sugar => pair.sugar,
+    };
+    let mut best: Option<usize> = None;
+    for (k, pair) in pairs.iter().enumerate() {
+        match best {
+            Some(b) if (key(&pairs[b]), &pairs[b].lcm) <= (key(pair), &pair.lcm) => {}
+            _ => best = Some(k),
+        }
+    }
+    best.map(|k| pairs.swap_remove(k))
+}
+
+/// Gebauer–Möller update after adding `polys[h]` to the basis: queues the new
+/// pairs that survive Buchberger's criteria, discards the old pairs made
+/// useless by the chain criterion and deactivates the elements whose leading
+/// monomial is divisible by the new one.
+pub(crate) fn update<'lt, F: Field + Debug + Display>(
+    polys: &[Polynomial<'lt, F>],
+    sugars: &[u64],
+    active: &mut Vec<bool>,
+    pairs: &mut Vec<Pair<'lt, F>>,
+    h: usize,
+) {
+    let lm_h = polys[h].lm();
+    let mut candidates: Vec<(Pair<F>, bool)> = (0..h)
+        .filter(|g| active[*g])
+        .map(|g| {
+            let lm_g = polys[g].lm();
+            let lcm = lm_h.lcm(&lm_g);
+            let sugar = max(
+                sugars[g] + lcm.degree() - lm_g.degree(),
+                sugars[h] + lcm.degree() - lm_h.degree(),
+            );
+            let pair = Pair {
+                i: g,
+                j: h,
+                lcm,
+                sugar,
+            };
+            (pair, lm_h.gcd(&lm_g).is_one())
+        })
+        .collect();
+
+    let mut kept = Vec::new();
+    while let Some((pair, coprime)) = candidates.pop() {
+        if coprime
+            || !candidates
+                .iter()
+                .chain(kept.iter())
+                .any(|(other, _)| pair.lcm.is_divisible(&other.lcm))
+        {
+            kept.push((pair, coprime));
+        }
+    }
+
+    pairs.retain(|pair| {
+        !pair.lcm.is_divisible(&lm_h)
+            || polys[pair.i].lm().lcm(&lm_h) == pair.lcm
+            || polys[pair.j].lm().lcm(&lm_h) == pair.lcm
+    });
+    pairs.extend(
+        kept.into_iter()
+            .filter(|(_, coprime)| !coprime)
+            .map(|(pair, _)| pair),
+    );
+
+    for g in 0..h {
+        if active[g] && polys[g].lm().is_divisible(&lm_h) {
+            active[g] = false;
         }
     }
+    active.push(true);
 }
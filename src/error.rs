@@ -0,0 +1,25 @@
+use std::error;
+use std::fmt;
+
+/// Errors of the computations that only apply to some ideals.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Error {
+    /// The quotient ring `k[x]/I` is not a finite dimensional vector space.
+    NotZeroDimensional,
+    /// The computation requires a basis for the lexicographic order.
+    NotLex,
+    /// The ideal is not equal to its radical.
+    NotRadical,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotZeroDimensional => write!(f, "ideal is not zero-dimensional"),
+            Error::NotLex => write!(f, "basis is not for the lex order"),
+            Error::NotRadical => write!(f, "ideal is not radical"),
+        }
+    }
+}
+
+impl error::Error for Error {}
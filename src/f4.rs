@@ -0,0 +1,245 @@
+use crate::buchberger::{update, Pair};
+use crate::{Field, Monomial, Polynomial};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+
+/// Sparse matrix row: nonzero entries sorted by increasing column, column 0
+/// being the greatest monomial, and the sugar of the polynomial it stands
+/// for.
+#[derive(Clone)]
+struct Row<F: Field> {
+    entries: Vec<(usize, F)>,
+    sugar: u64,
+}
+
+/// Reduced forms of the multiples `t * polys[k]` met in previous matrices,
+/// with their sugar, indexed by `k` and `t`. They have the same leading
+/// monomial as the multiple and are reused as reducers by `simplify`.
+type Simplified<'lt, F> = Vec<HashMap<Monomial<'lt, F>, (Polynomial<'lt, F>, u64)>>;
+
+/// Completes `polys` into a Gröbner basis with Faugère's F4 algorithm. All the
+/// critical pairs of minimal sugar are reduced at once by Gaussian
+/// elimination on a sparse Macaulay matrix built from monomial multiples of
+/// the basis.
+pub fn f4<F: Field + Debug + Display>(polys: &mut Vec<Polynomial<F>>) {
+    polys.retain(|p| !p.is_zero());
+    let mut sugars: Vec<u64> = polys.iter().map(|p| p.degree()).collect();
+    let mut simplified: Simplified<F> = vec![HashMap::new(); polys.len()];
+    let mut active = Vec::new();
+    let mut pairs = Vec::new();
+    for h in 0..polys.len() {
+        update(polys, &sugars, &mut active, &mut pairs, h);
+    }
+    while let Some(sugar) = pairs.iter().map(|pair| pair.sugar).min() {
+        let (batch, rest): (Vec<_>, Vec<_>) =
+            pairs.into_iter().partition(|pair| pair.sugar == sugar);
+        pairs = rest;
+        for (p, sugar) in reduction(polys, &sugars, &batch, &mut simplified) {
+            polys.push(p);
+            sugars.push(sugar);
+            simplified.push(HashMap::new());
+            update(polys, &sugars, &mut active, &mut pairs, polys.len() - 1);
+        }
+    }
+}
+
+/// Reduces the S-polynomials of `batch` simultaneously and returns the
+/// results whose leading monomials are not yet in the leading ideal, with
+/// their sugar.
+fn reduction<'lt, F: Field + Debug + Display>(
+    polys: &[Polynomial<'lt, F>],
+    sugars: &[u64],
+    batch: &[Pair<'lt, F>],
+    simplified: &mut Simplified<'lt, F>,
+) -> Vec<(Polynomial<'lt, F>, u64)> {
+    let poly_ring = polys[0].poly_ring();
+
+    // Multiples `t * polys[k]` reduced in this matrix, given by (k, t).
+    let mut seen = HashSet::new();
+    let mut origins = Vec::new();
+    let mut rows = Vec::new();
+    for pair in batch {
+        for k in [pair.i, pair.j] {
+            let t = &pair.lcm / &polys[k].lm();
+            if seen.insert((k, t.clone())) {
+                rows.push(simplify(polys, sugars, simplified, k, &t));
+                origins.push((k, t));
+            }
+        }
+    }
+
+    // Symbolic preprocessing: add a reducer for every monomial that can be
+    // reduced by the basis. As in Buchberger's algorithm the oldest divisor
+    // is used, even if it is no longer needed in the basis: its tail is
+    // usually the shortest, which matters for orders like lex.
+    let mut done: HashSet<Monomial<F>> = rows.iter().map(|r| r.0.lm()).collect();
+    let mut todo: Vec<Monomial<F>> = rows
+        .iter()
+        .flat_map(|r| r.0.terms().iter().map(|t| t.1.clone()))
+        .collect();
+    let mut reducers = Vec::new();
+    while let Some(m) = todo.pop() {
+        if !done.insert(m.clone()) {
+            continue;
+        }
+        let divisor = (0..polys.len()).find(|k| m.is_divisible(&polys[*k].lm()));
+        if let Some(k) = divisor {
+            let r = simplify(polys, sugars, simplified, k, &(&m / &polys[k].lm()));
+            todo.extend(
+                r.0.terms()
+                    .iter()
+                    .skip(1)
+                    .filter(|t| !done.contains(&t.1))
+                    .map(|t| t.1.clone()),
+            );
+            reducers.push(r);
+        }
+    }
+
+    let mut monomials: Vec<Monomial<F>> = done.into_iter().collect();
+    monomials.sort_by(|a, b| b.cmp(a));
+    let columns: HashMap<Monomial<F>, usize> = monomials
+        .iter()
+        .enumerate()
+        .map(|(c, m)| (m.clone(), c))
+        .collect();
+    let to_row = |(p, sugar): &(Polynomial<'lt, F>, u64)| Row {
+        entries: p
+            .terms()
+            .iter()
+            .map(|t| (columns[&t.1], t.0.clone()))
+            .collect(),
+        sugar: *sugar,
+    };
+    let to_poly = |row: &Row<F>| {
+        let terms = row
+            .entries
+            .iter()
+            .map(|(c, x)| (x.clone(), monomials[*c].clone()))
+            .collect();
+        (Polynomial::new(poly_ring, terms), row.sugar)
+    };
+
+    // Reducers have distinct leading monomials and are used as pivots as is.
+    let mut pivots: Vec<Option<Row<F>>> = vec![None; monomials.len()];
+    for r in &reducers {
+        let row = normalize(to_row(r));
+        let lead = row.entries[0].0;
+        pivots[lead] = Some(row);
+    }
+    let known: HashSet<usize> = rows
+        .iter()
+        .chain(reducers.iter())
+        .map(|r| columns[&r.0.lm()])
+        .collect();
+
+    // Forward elimination of the S-polynomial rows. A row keeping its
+    // leading monomial is a reduced form of its multiple, to be reused.
+    let mut new = Vec::new();
+    let mut kept = Vec::new();
+    for (r, origin) in rows.iter().zip(origins) {
+        let row = reduce_row(to_row(r), 0, &pivots);
+        if row.entries.is_empty() {
+            continue;
+        }
+        let row = normalize(row);
+        let lead = row.entries[0].0;
+        if !known.contains(&lead) {
+            new.push(lead);
+        } else if lead == columns[&r.0.lm()] {
+            kept.push((lead, origin));
+        }
+        pivots[lead] = Some(row);
+    }
+
+    // Back substitution on the S-polynomial rows, starting from the smallest
+    // leading monomial, so that their tails are reduced by the later pivots
+    // too.
+    let mut leads: Vec<usize> = new
+        .iter()
+        .chain(kept.iter().map(|k| &k.0))
+        .cloned()
+        .collect();
+    leads.sort_unstable_by(|a, b| b.cmp(a));
+    for lead in leads {
+        let row = pivots[lead].take().unwrap();
+        pivots[lead] = Some(reduce_row(row, 1, &pivots));
+    }
+
+    for (lead, (k, t)) in kept {
+        let r = to_poly(pivots[lead].as_ref().unwrap());
+        simplified[k].insert(t, r);
+    }
+    new.iter()
+        .map(|lead| to_poly(pivots[*lead].as_ref().unwrap()))
+        .collect()
+}
+
+/// Multiple `t * polys[k]` with its sugar, or the same multiple of a reduced
+/// form of a multiple `u * polys[k]` with `u` dividing `t` when one is known,
+/// choosing the `u` of highest degree (Faugère's Simplify).
+fn simplify<'lt, F: Field + Debug + Display>(
+    polys: &[Polynomial<'lt, F>],
+    sugars: &[u64],
+    simplified: &Simplified<'lt, F>,
+    k: usize,
+    t: &Monomial<'lt, F>,
+) -> (Polynomial<'lt, F>, u64) {
+    let best = simplified[k]
+        .iter()
+        .filter(|(u, _)| t.is_divisible(u))
+        .max_by_key(|(u, _)| u.degree());
+    let (u, p, sugar) = match best {
+        Some((u, (p, sugar))) => (t / u, p, *sugar),
+        None => (t.clone(), &polys[k], sugars[k]),
+    };
+    let sugar = sugar + u.degree();
+    if u.is_one() {
+        return (p.clone(), sugar);
+    }
+    let p = Polynomial::new(p.poly_ring(), vec![(F::one(), u)]) * p.clone();
+    (p, sugar)
+}
+
+/// Divides `row` by its leading coefficient.
+fn normalize<F: Field>(mut row: Row<F>) -> Row<F> {
+    let inv = F::one() / row.entries[0].1.clone();
+    for entry in &mut row.entries {
+        entry.1 *= inv.clone();
+    }
+    row
+}
+
+/// Eliminates from `row` every column that has a pivot, except for its
+/// first `skip` entries. Pivots are normalized.
+fn reduce_row<F: Field>(mut row: Row<F>, skip: usize, pivots: &[Option<Row<F>>]) -> Row<F> {
+    let start = match row.entries.get(skip) {
+        Some(entry) => entry.0,
+        None => return row,
+    };
+    // Dense accumulator for the columns from start on.
+    let mut dense = vec![F::zero(); pivots.len() - start];
+    for (c, x) in row.entries.drain(skip..) {
+        dense[c - start] = x;
+    }
+    for c in start..pivots.len() {
+        if dense[c - start].is_zero() {
+            continue;
+        }
+        if let Some(pivot) = &pivots[c] {
+            let factor = std::mem::replace(&mut dense[c - start], F::zero());
+            for (col, x) in &pivot.entries[1..] {
+                dense[col - start] -= factor.clone() * x.clone();
+            }
+            row.sugar = row.sugar.max(pivot.sugar);
+        }
+    }
+    row.entries.extend(
+        dense
+            .into_iter()
+            .enumerate()
+            .filter(|(_, x)| !x.is_zero())
+            .map(|(c, x)| (c + start, x)),
+    );
+    row
+}
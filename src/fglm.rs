@@ -0,0 +1,74 @@
+use crate::univariate::{mul_vector, Echelon};
+use crate::{Error, Field, GroebnerBasis, Monomial, PolyRing, Polynomial};
+use std::fmt::{Debug, Display};
+
+/// Monomial of the target ring waiting to be processed, `variable` times the
+/// `staircase`-th element of the new staircase.
+struct Candidate<'lt, F: Field> {
+    monomial: Monomial<'lt, F>,
+    variable: usize,
+    staircase: usize,
+}
+
+/// Reduced Gröbner basis for the order of `target` of the zero-dimensional
+/// ideal with Gröbner basis `basis`, computed with the FGLM algorithm: the
+/// monomials of `target` are enumerated by increasing order and their normal
+/// forms modulo `basis` are searched for linear dependencies, which give the
+/// elements of the new basis. `target` must have the same variables as the
+/// ring of `basis`.
+pub fn fglm<'b, F: Field + Debug + Display>(
+    basis: &GroebnerBasis<F>,
+    target: &'b PolyRing<F>,
+) -> Result<GroebnerBasis<'b, F>, Error> {
+    assert_eq!(basis.poly_ring().variable_names(), target.variable_names());
+    let n = target.variable_count();
+    let (one, matrices) = basis.unit_vector_and_matrices()?;
+
+    // Echelon form of the normal forms of the staircase.
+    let mut echelon = Echelon::new();
+    let mut staircase: Vec<Monomial<F>> = Vec::new();
+    let mut vectors: Vec<Vec<F>> = Vec::new();
+    let mut polys: Vec<Polynomial<F>> = Vec::new();
+
+    let mut todo: Vec<Candidate<F>> = Vec::new();
+    let mut next = Some((target.monomial(vec![0; n]), one));
+    while let Some((m, v)) = next.take() {
+        match echelon.insert(&v) {
+            Some(combination) => {
+                // m is the combination of the staircase given by combination.
+                let mut terms = vec![(F::one(), m)];
+                for (c, s) in combination.into_iter().zip(staircase.iter()) {
+                    terms.push((-c, s.clone()));
+                }
+                polys.push(Polynomial::new(target, terms));
+            }
+            None => {
+                for variable in 0..n {
+                    let mut degrees = m.degrees().to_vec();
+                    degrees[variable] += 1;
+                    let monomial = target.monomial(degrees);
+                    if !todo.iter().any(|c| c.monomial == monomial) {
+                        todo.push(Candidate {
+                            monomial,
+                            variable,
+                            staircase: staircase.len(),
+                        });
+                    }
+                }
+                staircase.push(m);
+                vectors.push(v);
+            }
+        }
+
+        // Next candidate by increasing order, skipping the multiples of the
+        // leading monomials found so far.
+        todo.retain(|c| !polys.iter().any(|p| c.monomial.is_divisible(&p.lm())));
+        let best = (0..todo.len()).min_by(|a, b| todo[*a].monomial.cmp(&todo[*b].monomial));
+        if let Some(k) = best {
+            let c = todo.swap_remove(k);
+            let v = mul_vector(&matrices[c.variable], &vectors[c.staircase]);
+            next = Some((c.monomial, v));
+        }
+    }
+    Ok(GroebnerBasis::from_reduced(target, polys))
+}
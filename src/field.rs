@@ -277,3 +277,22 @@ where
     + One
 {
 }
+
+/// Image of the integer `n` in `F`.
+pub(crate) fn from_int<F: Field>(n: i64) -> F {
+    let mut res = F::zero();
+    let mut power = F::one();
+    let mut k = n.unsigned_abs();
+    while k > 0 {
+        if k & 1 == 1 {
+            res += power.clone();
+        }
+        power = power.clone() + power;
+        k >>= 1;
+    }
+    if n < 0 {
+        -res
+    } else {
+        res
+    }
+}
@@ -0,0 +1,298 @@
+use crate::buchberger::reduce_basis;
+use crate::{
+    groebner_with, Error, Field, GroebnerConfig, HilbertSeries, Monomial, MonomialOrder, PolyRing,
+    Polynomial,
+};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+use std::slice;
+use std::vec;
+
+/// Square matrix given by its rows.
+type Matrix<F> = Vec<Vec<F>>;
+
+/// Reduced Gröbner basis of an ideal of `poly_ring` for the ring's monomial
+/// order. Elements are monic and sorted by increasing leading monomial, so two
+/// bases of the same ring are equal exactly when they generate the same ideal.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GroebnerBasis<'lt, F: Field> {
+    poly_ring: &'lt PolyRing<F>,
+    polys: Vec<Polynomial<'lt, F>>,
+}
+
+impl<'lt, F: Field + Debug + Display> GroebnerBasis<'lt, F> {
+    /// Computes the reduced Gröbner basis of the ideal generated by
+    /// `generators`.
+    pub fn new(poly_ring: &'lt PolyRing<F>, generators: Vec<Polynomial<'lt, F>>) -> Self {
+        GroebnerBasis::with_config(poly_ring, generators, &GroebnerConfig::default())
+    }
+
+    pub fn with_config(
+        poly_ring: &'lt PolyRing<F>,
+        generators: Vec<Polynomial<'lt, F>>,
+        config: &GroebnerConfig,
+    ) -> Self {
+        for g in &generators {
+            assert_eq!(g.poly_ring(), poly_ring);
+        }
+        let mut polys = generators;
+        groebner_with(&mut polys, config);
+        reduce_basis(&mut polys);
+        GroebnerBasis { poly_ring, polys }
+    }
+
+    /// Wraps `polys`, which must already be a reduced Gröbner basis.
+    pub(crate) fn from_reduced(
+        poly_ring: &'lt PolyRing<F>,
+        mut polys: Vec<Polynomial<'lt, F>>,
+    ) -> Self {
+        polys.sort_by_key(|p| p.lm());
+        GroebnerBasis { poly_ring, polys }
+    }
+
+    pub fn poly_ring(&self) -> &'lt PolyRing<F> {
+        self.poly_ring
+    }
+
+    pub fn order(&self) -> &'lt MonomialOrder {
+        self.poly_ring.order()
+    }
+
+    pub fn polys(&self) -> &[Polynomial<'lt, F>] {
+        &self.polys
+    }
+
+    pub fn len(&self) -> usize {
+        self.polys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.polys.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Polynomial<'lt, F>> {
+        self.polys.iter()
+    }
+
+    /// Unique remainder of `p` modulo the ideal.
+    pub fn normal_form(&self, p: &Polynomial<'lt, F>) -> Polynomial<'lt, F> {
+        assert_eq!(p.poly_ring(), self.poly_ring);
+        p.divide_by(&self.polys).1
+    }
+
+    /// Ideal membership test.
+    pub fn contains(&self, p: &Polynomial<'lt, F>) -> bool {
+        self.normal_form(p).is_zero()
+    }
+
+    /// Minimal generators of the ideal of leading monomials.
+    pub fn leading_ideal(&self) -> Vec<Monomial<'lt, F>> {
+        self.polys.iter().map(|p| p.lm()).collect()
+    }
+
+    /// Whether `k[x]/I` is finite dimensional, that is whether every
+    /// variable has a pure power among the leading monomials.
+    pub fn is_zero_dimensional(&self) -> bool {
+        let lms = self.leading_ideal();
+        (0..self.poly_ring.variable_count()).all(|i| {
+            lms.iter().any(|m| {
+                m.degrees()
+                    .iter()
+                    .enumerate()
+                    .all(|(j, d)| j == i || *d == 0)
+            })
+        })
+    }
+
+    /// Monomials outside the leading ideal, by increasing order. Their
+    /// classes form a basis of `k[x]/I`.
+    pub fn standard_monomials(&self) -> Result<Vec<Monomial<'lt, F>>, Error> {
+        if !self.is_zero_dimensional() {
+            return Err(Error::NotZeroDimensional);
+        }
+        let lms = self.leading_ideal();
+        let is_standard = |m: &Monomial<'lt, F>| !lms.iter().any(|lm| m.is_divisible(lm));
+        let n = self.poly_ring.variable_count();
+        let one = self.poly_ring.monomial(vec![0; n]);
+        let mut res = Vec::new();
+        let mut todo = Vec::new();
+        if is_standard(&one) {
+            todo.push(one);
+        }
+        let mut seen: HashSet<Monomial<F>> = todo.iter().cloned().collect();
+        while let Some(m) = todo.pop() {
+            for i in 0..n {
+                let mut degrees = m.degrees().to_vec();
+                degrees[i] += 1;
+                let next = self.poly_ring.monomial(degrees);
+                if is_standard(&next) && seen.insert(next.clone()) {
+                    todo.push(next);
+                }
+            }
+            res.push(m);
+        }
+        res.sort();
+        Ok(res)
+    }
+
+    /// Dimension of `k[x]/I` as a vector space.
+    pub fn quotient_dimension(&self) -> Result<usize, Error> {
+        self.standard_monomials().map(|b| b.len())
+    }
+
+    /// Matrix of the multiplication by `f` on `k[x]/I` in the basis of
+    /// standard monomials: `m[r][c]` is the coefficient of the `r`-th
+    /// standard monomial in the normal form of `f` times the `c`-th one.
+    pub fn multiplication_matrix(&self, f: &Polynomial<'lt, F>) -> Result<Vec<Vec<F>>, Error> {
+        assert_eq!(f.poly_ring(), self.poly_ring);
+        let basis = self.standard_monomials()?;
+        Ok(self.matrix_in(f, &basis, &index_of(&basis)))
+    }
+
+    /// Matrices of the multiplications by each variable, see
+    /// `multiplication_matrix`. They commute and their joint eigenvalues are
+    /// the coordinates of the points of the variety.
+    pub fn multiplication_matrices(&self) -> Result<Vec<Vec<Vec<F>>>, Error> {
+        self.unit_vector_and_matrices()
+            .map(|(_, matrices)| matrices)
+    }
+
+    /// Coordinates of 1 in the basis of standard monomials, zero for the
+    /// unit ideal, and the matrices of `multiplication_matrices`, sharing a
+    /// single computation of the standard monomials.
+    pub(crate) fn unit_vector_and_matrices(&self) -> Result<(Vec<F>, Vec<Matrix<F>>), Error> {
+        let basis = self.standard_monomials()?;
+        let index = index_of(&basis);
+        let mut one = vec![F::zero(); basis.len()];
+        if let Some(x) = one.first_mut() {
+            // Standard monomials are sorted, so 1 comes first.
+            *x = F::one();
+        }
+        let matrices = self
+            .poly_ring
+            .variables()
+            .iter()
+            .map(|x| self.matrix_in(x, &basis, &index))
+            .collect();
+        Ok((one, matrices))
+    }
+
+    /// Multiplication matrix of `f` in the standard monomials `basis`, whose
+    /// positions are given by `index`.
+    fn matrix_in(
+        &self,
+        f: &Polynomial<'lt, F>,
+        basis: &[Monomial<'lt, F>],
+        index: &HashMap<Monomial<'lt, F>, usize>,
+    ) -> Vec<Vec<F>> {
+        let mut res = vec![vec![F::zero(); basis.len()]; basis.len()];
+        for (c, b) in basis.iter().enumerate() {
+            let p = Polynomial::new(self.poly_ring, vec![(F::one(), b.clone())]) * f.clone();
+            for (x, m) in self.normal_form(&p).terms() {
+                res[index[m]][c] = x.clone();
+            }
+        }
+        res
+    }
+
+    /// Hilbert series of `k[x]/in(I)`, which is that of `k[x]/I` for a
+    /// homogeneous ideal. For an affine ideal and a degree compatible order,
+    /// its partial sums give the affine Hilbert function of `I`.
+    pub fn hilbert_series(&self) -> HilbertSeries {
+        HilbertSeries::new(self.poly_ring.variable_count(), &self.leading_ideal())
+    }
+
+    /// Maximal (for inclusion) sets of variable indices independent modulo
+    /// the leading ideal, i.e. such that no leading monomial involves only
+    /// variables of the set. Empty when the ideal is the whole ring.
+    pub fn independent_sets(&self) -> Vec<Vec<usize>> {
+        let supports: Vec<Vec<usize>> = self
+            .polys
+            .iter()
+            .map(|p| {
+                let lm = p.lm();
+                (0..lm.variable_count())
+                    .filter(|i| lm.degrees()[*i] > 0)
+                    .collect()
+            })
+            .collect();
+        if supports.iter().any(|s| s.is_empty()) {
+            return Vec::new();
+        }
+        let mut res = Vec::new();
+        let mut set = Vec::new();
+        search_independent(
+            &supports,
+            self.poly_ring.variable_count(),
+            0,
+            &mut set,
+            &mut res,
+        );
+        res
+    }
+
+    /// Krull dimension of the quotient ring, i.e. the dimension of the
+    /// variety of the ideal, with an independent set of that size. `None`
+    /// when the ideal is the whole ring and the variety is empty.
+    pub fn dimension(&self) -> Option<(usize, Vec<usize>)> {
+        self.independent_sets()
+            .into_iter()
+            .max_by_key(|s| s.len())
+            .map(|s| (s.len(), s))
+    }
+}
+
+impl<'a, 'lt, F: Field> IntoIterator for &'a GroebnerBasis<'lt, F> {
+    type Item = &'a Polynomial<'lt, F>;
+    type IntoIter = slice::Iter<'a, Polynomial<'lt, F>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.polys.iter()
+    }
+}
+
+impl<'lt, F: Field> IntoIterator for GroebnerBasis<'lt, F> {
+    type Item = Polynomial<'lt, F>;
+    type IntoIter = vec::IntoIter<Polynomial<'lt, F>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.polys.into_iter()
+    }
+}
+
+/// Position of each monomial of `basis`.
+fn index_of<'lt, F: Field>(basis: &[Monomial<'lt, F>]) -> HashMap<Monomial<'lt, F>, usize> {
+    basis
+        .iter()
+        .enumerate()
+        .map(|(k, m)| (m.clone(), k))
+        .collect()
+}
+
+/// Enumerates the maximal independent sets extending `set` with variables
+/// from `next` on.
+fn search_independent(
+    supports: &[Vec<usize>],
+    count: usize,
+    next: usize,
+    set: &mut Vec<usize>,
+    res: &mut Vec<Vec<usize>>,
+) {
+    let independent = |set: &[usize]| !supports.iter().any(|s| s.iter().all(|i| set.contains(i)));
+    for i in next..count {
+        set.push(i);
+        if independent(set) {
+            search_independent(supports, count, i + 1, set, res);
+        }
+        set.pop();
+    }
+    let mut maximal = true;
+    for i in 0..count {
+        if !set.contains(&i) {
+            set.push(i);
+            maximal &= !independent(set);
+            set.pop();
+        }
+    }
+    if maximal {
+        res.push(set.clone());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::field::from_int;
+use crate::{Field, Monomial};
+use std::fmt::{Debug, Display};
+
+/// Hilbert–Poincaré series `numerator(t) / (1 - t)^n` of `k[x]/M` for a
+/// monomial ideal `M` of a ring with `n` variables, graded by total degree.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct HilbertSeries {
+    numerator: Vec<i64>,
+    variable_count: usize,
+}
+
+/// Hilbert polynomial `Σ q[k] * C(s - k + d - 1, d - 1)` where
+/// `Σ q[k] * t^k / (1 - t)^d` is the reduced form of a Hilbert series.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct HilbertPolynomial {
+    numerator: Vec<i64>,
+    dimension: usize,
+}
+
+impl HilbertSeries {
+    /// Series of `k[x]/<generators>`.
+    pub fn new<F: Field + Debug + Display>(
+        variable_count: usize,
+        generators: &[Monomial<F>],
+    ) -> Self {
+        for m in generators {
+            assert_eq!(m.variable_count(), variable_count);
+        }
+        HilbertSeries {
+            numerator: trim(numerator(generators)),
+            variable_count,
+        }
+    }
+
+    /// Coefficients of the numerator by increasing power of `t`.
+    pub fn numerator(&self) -> &[i64] {
+        &self.numerator
+    }
+
+    pub fn variable_count(&self) -> usize {
+        self.variable_count
+    }
+
+    /// Numerator and exponent of the denominator once the common factors
+    /// `1 - t` are cancelled. The exponent is the dimension of the ideal, 0
+    /// for the unit ideal whose series is zero.
+    pub fn reduced(&self) -> (Vec<i64>, usize) {
+        if self.numerator.is_empty() {
+            return (Vec::new(), 0);
+        }
+        let mut numerator = self.numerator.clone();
+        let mut dimension = self.variable_count;
+        while dimension > 0 && numerator.iter().sum::<i64>() == 0 {
+            // Division by 1 - t: the quotient coefficients are the partial
+            // sums of the numerator.
+            let mut sum = 0;
+            for c in &mut numerator {
+                sum += *c;
+                *c = sum;
+            }
+            numerator.pop();
+            numerator = trim(numerator);
+            dimension -= 1;
+        }
+        (numerator, dimension)
+    }
+
+    /// Degree (multiplicity) of the ideal: the reduced numerator at `t = 1`.
+    pub fn degree(&self) -> i64 {
+        self.reduced().0.iter().sum()
+    }
+
+    /// Value of the Hilbert function at `s`, that is the number of standard
+    /// monomials of degree `s`.
+    pub fn value(&self, s: u64) -> i64 {
+        let n = self.variable_count as i64;
+        if n == 0 {
+            return self.numerator.get(s as usize).cloned().unwrap_or(0);
+        }
+        self.numerator
+            .iter()
+            .enumerate()
+            .filter(|(k, _)| *k as u64 <= s)
+            .map(|(k, c)| c * binomial(s as i64 - k as i64 + n - 1, n - 1))
+            .sum()
+    }
+
+    /// The polynomial agreeing with the Hilbert function in high degrees.
+    pub fn hilbert_polynomial(&self) -> HilbertPolynomial {
+        let (numerator, dimension) = self.reduced();
+        HilbertPolynomial {
+            numerator,
+            dimension,
+        }
+    }
+}
+
+impl HilbertPolynomial {
+    /// Degree of the polynomial plus one, the dimension of the ideal. The
+    /// polynomial is zero for zero-dimensional ideals.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn eval(&self, s: i64) -> i64 {
+        if self.dimension == 0 {
+            return 0;
+        }
+        let d = self.dimension as i64;
+        self.numerator
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c * binomial(s - k as i64 + d - 1, d - 1))
+            .sum()
+    }
+
+    /// Coefficients in `F` by increasing power of `s`.
+    pub fn coefficients<F: Field>(&self) -> Vec<F> {
+        if self.dimension == 0 {
+            return Vec::new();
+        }
+        let d = self.dimension;
+        let mut res = vec![F::zero(); d];
+        for (k, c) in self.numerator.iter().enumerate() {
+            // C(s - k + d - 1, d - 1) = Π_{j=1}^{d-1} (s - k + j) / j
+            let mut term = vec![F::one()];
+            for j in 1..d {
+                let shift = from_int::<F>(j as i64 - k as i64);
+                let inv = F::one() / from_int::<F>(j as i64);
+                let mut next = vec![F::zero(); term.len() + 1];
+                for (i, a) in term.iter().enumerate() {
+                    next[i] += a.clone() * shift.clone() * inv.clone();
+                    next[i + 1] += a.clone() * inv.clone();
+                }
+                term = next;
+            }
+            for (i, a) in term.into_iter().enumerate() {
+                res[i] += a * from_int::<F>(*c);
+            }
+        }
+        res
+    }
+}
+
+/// Numerator of the Hilbert series of `k[x]/<generators>`, computed with
+/// `N(M + <m>) = N(M) - t^deg(m) * N(M : m)`.
+fn numerator<F: Field + Debug + Display>(generators: &[Monomial<F>]) -> Vec<i64> {
+    let generators = minimalize(generators);
+    // Pairwise coprime generators form a regular sequence.
+    let coprime =
+        (0..generators.len()).all(|i| (0..i).all(|j| generators[i].gcd(&generators[j]).is_one()));
+    if coprime {
+        let mut res = vec![1];
+        for m in &generators {
+            let shifted = shift(&res, m.degree() as usize);
+            res = sub(&res, &shifted);
+        }
+        return res;
+    }
+    let (m, rest) = generators.split_last().unwrap();
+    let quotient: Vec<Monomial<F>> = rest.iter().map(|g| &g.lcm(m) / m).collect();
+    sub(
+        &numerator(rest),
+        &shift(&numerator(&quotient), m.degree() as usize),
+    )
+}
+
+/// Minimal generators of the monomial ideal generated by `generators`.
+fn minimalize<'lt, F: Field + Debug + Display>(
+    generators: &[Monomial<'lt, F>],
+) -> Vec<Monomial<'lt, F>> {
+    let mut res: Vec<Monomial<F>> = Vec::new();
+    for m in generators {
+        if res.iter().any(|g| m.is_divisible(g)) {
+            continue;
+        }
+        res.retain(|g| !g.is_divisible(m));
+        res.push(m.clone());
+    }
+    res
+}
+
+fn shift(a: &[i64], k: usize) -> Vec<i64> {
+    let mut res = vec![0; k];
+    res.extend_from_slice(a);
+    res
+}
+
+fn sub(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut res = vec![0; a.len().max(b.len())];
+    for (i, c) in a.iter().enumerate() {
+        res[i] += c;
+    }
+    for (i, c) in b.iter().enumerate() {
+        res[i] -= c;
+    }
+    res
+}
+
+/// Removes the trailing zero coefficients.
+fn trim(mut a: Vec<i64>) -> Vec<i64> {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+    a
+}
+
+/// `C(a, r)` for any integer `a`, as a polynomial in `a`.
+fn binomial(a: i64, r: i64) -> i64 {
+    let mut res: i128 = 1;
+    for j in 0..r {
+        res = res * (a - j) as i128 / (j + 1) as i128;
+    }
+    res as i64
+}
@@ -0,0 +1,235 @@
+use crate::{Field, GroebnerBasis, MonomialOrder, PolyRing, Polynomial};
+use std::fmt::{Debug, Display};
+use std::slice;
+
+/// Ideal of a `PolyRing` given by a finite set of generators.
+#[derive(Debug, Clone)]
+pub struct Ideal<'lt, F: Field> {
+    poly_ring: &'lt PolyRing<F>,
+    generators: Vec<Polynomial<'lt, F>>,
+}
+
+impl<'lt, F: Field + Debug + Display> Ideal<'lt, F> {
+    pub fn new(poly_ring: &'lt PolyRing<F>, generators: Vec<Polynomial<'lt, F>>) -> Self {
+        for g in &generators {
+            assert_eq!(g.poly_ring(), poly_ring);
+        }
+        Ideal {
+            poly_ring,
+            generators,
+        }
+    }
+
+    pub fn poly_ring(&self) -> &'lt PolyRing<F> {
+        self.poly_ring
+    }
+
+    pub fn generators(&self) -> &[Polynomial<'lt, F>] {
+        &self.generators
+    }
+
+    pub fn groebner_basis(&self) -> GroebnerBasis<'lt, F> {
+        GroebnerBasis::new(self.poly_ring, self.generators.clone())
+    }
+
+    /// Ideal membership test.
+    pub fn contains(&self, p: &Polynomial<'lt, F>) -> bool {
+        self.groebner_basis().contains(p)
+    }
+
+    /// Dimension of the variety of the ideal, see
+    /// `GroebnerBasis::dimension`.
+    pub fn dimension(&self) -> Option<(usize, Vec<usize>)> {
+        self.groebner_basis().dimension()
+    }
+
+    /// Tests `I ⊆ J` by reducing the generators of `I` modulo `J`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        assert_eq!(self.poly_ring, other.poly_ring);
+        let basis = other.groebner_basis();
+        self.generators.iter().all(|g| basis.contains(g))
+    }
+
+    /// Tests whether a power of `p` lies in the ideal, that is whether
+    /// `1 ∈ I + <1 - t * p>` with `t` a new variable.
+    pub fn radical_contains(&self, p: &Polynomial<'lt, F>) -> bool {
+        assert_eq!(p.poly_ring(), self.poly_ring);
+        let poly_ring = self.poly_ring.extend(vec!["t"]);
+        let t = poly_ring.variables().remove(0);
+        let mut generators: Vec<Polynomial<F>> =
+            self.generators.iter().map(|g| poly_ring.embed(g)).collect();
+        generators.push(-(t * poly_ring.embed(p)) + F::one());
+        GroebnerBasis::new(&poly_ring, generators)
+            .iter()
+            .any(|g| g.is_one())
+    }
+
+    /// `I + J`
+    pub fn sum(&self, other: &Self) -> Self {
+        assert_eq!(self.poly_ring, other.poly_ring);
+        let generators = self
+            .generators
+            .iter()
+            .chain(other.generators.iter())
+            .cloned()
+            .collect();
+        Ideal::new(self.poly_ring, generators)
+    }
+
+    /// `I * J`
+    pub fn product(&self, other: &Self) -> Self {
+        assert_eq!(self.poly_ring, other.poly_ring);
+        let mut generators = Vec::new();
+        for f in &self.generators {
+            for g in &other.generators {
+                generators.push(f.clone() * g.clone());
+            }
+        }
+        Ideal::new(self.poly_ring, generators)
+    }
+
+    /// `I ∩ J`, computed as `(t * I + (1 - t) * J) ∩ k[x]`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        assert_eq!(self.poly_ring, other.poly_ring);
+        let poly_ring = self.poly_ring.extend(vec!["t"]);
+        let t = poly_ring.variables().remove(0);
+        let mut generators = Vec::new();
+        for f in &self.generators {
+            generators.push(t.clone() * poly_ring.embed(f));
+        }
+        for g in &other.generators {
+            generators.push((-t.clone() + F::one()) * poly_ring.embed(g));
+        }
+        self.eliminate_leading(&poly_ring, 1, generators)
+    }
+
+    /// Ideal quotient `I : J = { f | f * J ⊆ I }`.
+    pub fn quotient(&self, other: &Self) -> Self {
+        assert_eq!(self.poly_ring, other.poly_ring);
+        let mut res = None;
+        for g in other.generators.iter().filter(|g| !g.is_zero()) {
+            // I : g = (I ∩ <g>) / g
+            let principal = Ideal::new(self.poly_ring, vec![g.clone()]);
+            let generators = self
+                .intersection(&principal)
+                .generators
+                .iter()
+                .map(|h| h.divide_by(slice::from_ref(g)).0.remove(0))
+                .collect();
+            res = Some(intersect(res, Ideal::new(self.poly_ring, generators)));
+        }
+        res.unwrap_or_else(|| self.unit())
+    }
+
+    /// Saturation `I : J^∞ = { f | f * J^k ⊆ I for some k }`.
+    pub fn saturation(&self, other: &Self) -> Self {
+        assert_eq!(self.poly_ring, other.poly_ring);
+        let mut res = None;
+        for g in other.generators.iter().filter(|g| !g.is_zero()) {
+            // I : g^∞ = (I + <1 - t * g>) ∩ k[x]
+            let poly_ring = self.poly_ring.extend(vec!["t"]);
+            let t = poly_ring.variables().remove(0);
+            let mut generators: Vec<Polynomial<F>> =
+                self.generators.iter().map(|f| poly_ring.embed(f)).collect();
+            generators.push(-(t * poly_ring.embed(g)) + F::one());
+            res = Some(intersect(
+                res,
+                self.eliminate_leading(&poly_ring, 1, generators),
+            ));
+        }
+        res.unwrap_or_else(|| self.unit())
+    }
+
+    /// The ideal `<1>` of this ideal's ring.
+    fn unit(&self) -> Self {
+        let one = Polynomial::new(self.poly_ring, Vec::new()) + F::one();
+        Ideal::new(self.poly_ring, vec![one])
+    }
+
+    /// Generators of the ideal generated by `generators` in `poly_ring`
+    /// intersected with this ideal's ring, `poly_ring` extending it by a block
+    /// of `count` leading variables.
+    fn eliminate_leading(
+        &self,
+        poly_ring: &PolyRing<F>,
+        count: usize,
+        generators: Vec<Polynomial<F>>,
+    ) -> Self {
+        let mut map = vec![None; count];
+        map.extend((0..self.poly_ring.variable_count()).map(Some));
+        self.eliminate_block(poly_ring, count, generators, &map)
+    }
+
+    /// Generators of the ideal generated by `generators` in `poly_ring`, for
+    /// an order where the first `count` variables form a block greater than
+    /// the others, intersected with the ring of the other variables. They are
+    /// sent to this ideal's ring, variable `i` of `poly_ring` becoming
+    /// variable `map[i]`.
+    fn eliminate_block(
+        &self,
+        poly_ring: &PolyRing<F>,
+        count: usize,
+        generators: Vec<Polynomial<F>>,
+        map: &[Option<usize>],
+    ) -> Self {
+        let generators = GroebnerBasis::new(poly_ring, generators)
+            .into_iter()
+            .filter(|g| g.lm().degrees()[..count].iter().all(|d| *d == 0))
+            .map(|g| g.map_variables(self.poly_ring, map))
+            .collect();
+        Ideal::new(self.poly_ring, generators)
+    }
+}
+
+fn intersect<'lt, F: Field + Debug + Display>(
+    res: Option<Ideal<'lt, F>>,
+    other: Ideal<'lt, F>,
+) -> Ideal<'lt, F> {
+    match res {
+        Some(res) => res.intersection(&other),
+        None => other,
+    }
+}
+
+/// Ideals are equal when they have the same reduced Gröbner basis.
+impl<'lt, F: Field + Debug + Display> PartialEq for Ideal<'lt, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.groebner_basis() == other.groebner_basis()
+    }
+}
+
+impl<'lt, F: Field + Debug + Display> Eq for Ideal<'lt, F> {}
+
+/// Generators of the elimination ideal `I ∩ k[y]`, `y` being the variables of
+/// the ring of `ideal` whose index is not in `variables`. They are read off a
+/// Gröbner basis for a block order where the eliminated variables are greater
+/// than the others.
+pub fn eliminate<'lt, F: Field + Debug + Display>(
+    ideal: &Ideal<'lt, F>,
+    variables: &[usize],
+) -> Ideal<'lt, F> {
+    let names = ideal.poly_ring.variable_names();
+    let (eliminated, kept): (Vec<usize>, Vec<usize>) =
+        (0..names.len()).partition(|i| variables.contains(i));
+    let poly_ring = PolyRing::with_blocks(
+        vec![
+            eliminated.iter().map(|i| names[*i]).collect(),
+            kept.iter().map(|i| names[*i]).collect(),
+        ],
+        MonomialOrder::GradedRevLex,
+    );
+    let mut map = vec![None; names.len()];
+    let mut back = vec![None; names.len()];
+    for (k, i) in eliminated.iter().chain(kept.iter()).enumerate() {
+        map[*i] = Some(k);
+        if k >= eliminated.len() {
+            back[k] = Some(*i);
+        }
+    }
+    let generators = ideal
+        .generators
+        .iter()
+        .map(|g| g.map_variables(&poly_ring, &map))
+        .collect();
+    ideal.eliminate_block(&poly_ring, eliminated.len(), generators, &back)
+}
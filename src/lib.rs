@@ -1,11 +1,46 @@
 mod buchberger;
+mod error;
+mod f4;
+mod fglm;
 mod field;
+mod groebner_basis;
+mod hilbert;
+mod ideal;
 mod monomial;
+mod monomial_order;
 mod poly_ring;
+#[allow(
+    clippy::mem_replace_with_default,
+    clippy::needless_return,
+    clippy::redundant_field_names
+)]
 mod polynomial;
+mod real_roots;
+mod rur;
+mod signature;
+mod solve;
+mod triangular;
+mod univariate;
+mod walk;
 
-pub use buchberger::groebner;
+pub use buchberger::{
+    groebner, groebner_with, groebner_with_cofactors, reduced_groebner, GroebnerConfig,
+    SelectionStrategy,
+};
+pub use error::Error;
+pub use f4::f4;
+pub use fglm::fglm;
 pub use field::{Field, F};
+pub use groebner_basis::GroebnerBasis;
+pub use hilbert::{HilbertPolynomial, HilbertSeries};
+pub use ideal::{eliminate, Ideal};
 pub use monomial::Monomial;
+pub use monomial_order::MonomialOrder;
 pub use poly_ring::PolyRing;
 pub use polynomial::Polynomial;
+pub use real_roots::{isolate_real_roots, refine_root, RootInterval};
+pub use rur::{rational_univariate_representation, Rur};
+pub use signature::{signature_groebner, Signature};
+pub use solve::solve;
+pub use triangular::triangular_decompose;
+pub use walk::groebner_walk;
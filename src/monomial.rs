@@ -4,6 +4,7 @@ use std::fmt;
 use std::fmt::Debug;
 
 use std::cmp::{max, min, Ord, Ordering, PartialOrd};
+use std::hash::{Hash, Hasher};
 use std::ops::{Div, DivAssign, Mul, MulAssign};
 use std::vec::Vec;
 
@@ -16,27 +17,28 @@ pub struct Monomial<'lt, F: Field> {
 impl<'lt, F: Field + Debug + fmt::Display> Monomial<'lt, F> {
     pub fn new(poly_ring: &'lt PolyRing<F>, degrees: Vec<u64>) -> Monomial<'lt, F> {
         assert_eq!(poly_ring.variable_count(), degrees.len());
-        Monomial {
-            poly_ring: poly_ring,
-            degrees: degrees,
-        }
+        Monomial { poly_ring, degrees }
     }
 
     pub fn gcd(&self, other: &Self) -> Self {
         assert_eq!(self.variable_count(), other.variable_count());
-        let mut res = vec![0; self.variable_count()];
-        for i in 0..self.variable_count() {
-            res[i] = min(self.degrees[i], other.degrees[i]);
-        }
+        let res = self
+            .degrees
+            .iter()
+            .zip(&other.degrees)
+            .map(|(a, b)| min(*a, *b))
+            .collect();
         self.poly_ring.monomial(res)
     }
 
     pub fn lcm(&self, other: &Self) -> Self {
         assert_eq!(self.variable_count(), other.variable_count());
-        let mut res = vec![0; self.variable_count()];
-        for i in 0..self.variable_count() {
-            res[i] = max(self.degrees[i], other.degrees[i]);
-        }
+        let res = self
+            .degrees
+            .iter()
+            .zip(&other.degrees)
+            .map(|(a, b)| max(*a, *b))
+            .collect();
         self.poly_ring.monomial(res)
     }
 
@@ -47,7 +49,7 @@ impl<'lt, F: Field + Debug + fmt::Display> Monomial<'lt, F> {
                 return false;
             }
         }
-        return true;
+        true
     }
 }
 
@@ -56,6 +58,15 @@ impl<'lt, F: Field> Monomial<'lt, F> {
         self.degrees.len()
     }
 
+    pub fn degrees(&self) -> &[u64] {
+        &self.degrees
+    }
+
+    /// Total degree.
+    pub fn degree(&self) -> u64 {
+        self.degrees.iter().sum()
+    }
+
     pub fn is_one(&self) -> bool {
         let mut res = true;
         for d in &self.degrees {
@@ -63,13 +74,21 @@ impl<'lt, F: Field> Monomial<'lt, F> {
                 res = false;
             }
         }
-        return res;
+        res
     }
 }
 
 impl<'lt, F: Field> Ord for Monomial<'lt, F> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.degrees.cmp(&other.degrees)
+        self.poly_ring
+            .order()
+            .compare(&self.degrees, &other.degrees)
+    }
+}
+
+impl<'lt, F: Field> Hash for Monomial<'lt, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.degrees.hash(state);
     }
 }
 
@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+
+/// Term order used by a `PolyRing` to compare its monomials.
+///
+/// Variables are ranked in the order they were given to the ring, the first
+/// one being the greatest.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum MonomialOrder {
+    /// Lexicographic order.
+    #[default]
+    Lex,
+    /// Total degree first, ties broken lexicographically.
+    GradedLex,
+    /// Total degree first, ties broken by the smallest exponent in the last
+    /// differing variable.
+    GradedRevLex,
+    /// Weighted degree first, ties broken by the inner order.
+    Weighted(Vec<u64>, Box<MonomialOrder>),
+    /// Product order on consecutive blocks of variables given by their sizes.
+    /// Any difference in an earlier block decides the comparison, each block
+    /// being compared with its own order.
+    Block(Vec<(usize, MonomialOrder)>),
+    /// Lexicographic comparison of `M * degrees` for an integer matrix `M`
+    /// given by rows. `M` must have full column rank and the first nonzero
+    /// entry of each column must be positive.
+    Matrix(Vec<Vec<i64>>),
+}
+
+impl MonomialOrder {
+    pub fn weighted(weights: Vec<u64>, tie_break: MonomialOrder) -> MonomialOrder {
+        MonomialOrder::Weighted(weights, Box::new(tie_break))
+    }
+
+    /// Compares two exponent vectors of the same length.
+    pub fn compare(&self, a: &[u64], b: &[u64]) -> Ordering {
+        assert_eq!(a.len(), b.len());
+        match self {
+            MonomialOrder::Lex => a.cmp(b),
+            MonomialOrder::GradedLex => {
+                total_degree(a).cmp(&total_degree(b)).then_with(|| a.cmp(b))
+            }
+            MonomialOrder::GradedRevLex => total_degree(a)
+                .cmp(&total_degree(b))
+                .then_with(|| rev_lex(a, b)),
+            MonomialOrder::Weighted(weights, tie_break) => weighted_degree(weights, a)
+                .cmp(&weighted_degree(weights, b))
+                .then_with(|| tie_break.compare(a, b)),
+            MonomialOrder::Block(blocks) => {
+                let mut start = 0;
+                for (size, order) in blocks {
+                    let end = start + size;
+                    let res = order.compare(&a[start..end], &b[start..end]);
+                    if res != Ordering::Equal {
+                        return res;
+                    }
+                    start = end;
+                }
+                Ordering::Equal
+            }
+            MonomialOrder::Matrix(rows) => {
+                for row in rows {
+                    let res = dot(row, a).cmp(&dot(row, b));
+                    if res != Ordering::Equal {
+                        return res;
+                    }
+                }
+                Ordering::Equal
+            }
+        }
+    }
+
+    /// Whether the order compares monomials on `variable_count` variables
+    /// exactly like `Lex`.
+    pub(crate) fn is_lex(&self, variable_count: usize) -> bool {
+        match self {
+            MonomialOrder::Lex => true,
+            MonomialOrder::GradedLex | MonomialOrder::GradedRevLex => variable_count <= 1,
+            // The weighted degree must not depend on the smaller variables.
+            MonomialOrder::Weighted(weights, tie_break) => {
+                weights.iter().skip(1).all(|w| *w == 0) && tie_break.is_lex(variable_count)
+            }
+            MonomialOrder::Block(blocks) => blocks.iter().all(|(size, order)| order.is_lex(*size)),
+            // Each variable must be decided by an earlier row than all the
+            // smaller ones, where their columns are zero.
+            MonomialOrder::Matrix(rows) => {
+                let first: Vec<Option<usize>> = (0..variable_count)
+                    .map(|j| rows.iter().position(|row| row[j] != 0))
+                    .collect();
+                first.windows(2).all(|w| w[0] < w[1])
+            }
+        }
+    }
+
+    /// Weight vector refined by the order, the first row of a matrix
+    /// defining it.
+    pub(crate) fn weight(&self, variable_count: usize) -> Vec<u64> {
+        match self {
+            MonomialOrder::Lex => {
+                let mut res = vec![0; variable_count];
+                if variable_count > 0 {
+                    res[0] = 1;
+                }
+                res
+            }
+            MonomialOrder::GradedLex | MonomialOrder::GradedRevLex => vec![1; variable_count],
+            MonomialOrder::Weighted(weights, _) => weights.clone(),
+            MonomialOrder::Block(blocks) => {
+                let mut res = match blocks.first() {
+                    Some((size, order)) => order.weight(*size),
+                    None => Vec::new(),
+                };
+                res.resize(variable_count, 0);
+                res
+            }
+            MonomialOrder::Matrix(rows) => match rows.first() {
+                Some(row) => row.iter().map(|x| *x as u64).collect(),
+                None => vec![0; variable_count],
+            },
+        }
+    }
+
+    /// Panics if the order cannot be used on `variable_count` variables.
+    pub(crate) fn validate(&self, variable_count: usize) {
+        match self {
+            MonomialOrder::Weighted(weights, tie_break) => {
+                assert_eq!(
+                    weights.len(),
+                    variable_count,
+                    "One weight per variable is required"
+                );
+                tie_break.validate(variable_count);
+            }
+            MonomialOrder::Block(blocks) => {
+                let sizes: usize = blocks.iter().map(|(size, _)| size).sum();
+                assert_eq!(sizes, variable_count, "Blocks must cover every variable");
+                for (size, order) in blocks {
+                    order.validate(*size);
+                }
+            }
+            MonomialOrder::Matrix(rows) => {
+                for row in rows {
+                    assert_eq!(
+                        row.len(),
+                        variable_count,
+                        "One column per variable is required"
+                    );
+                }
+                for j in 0..variable_count {
+                    let first = rows.iter().map(|row| row[j]).find(|x| *x != 0);
+                    assert!(
+                        first.unwrap_or(0) > 0,
+                        "First nonzero entry of each column must be positive"
+                    );
+                }
+                assert_eq!(rank(rows), variable_count, "Matrix must have full rank");
+            }
+            _ => {}
+        }
+    }
+}
+
+fn total_degree(degrees: &[u64]) -> u64 {
+    degrees.iter().sum()
+}
+
+fn weighted_degree(weights: &[u64], degrees: &[u64]) -> u64 {
+    weights.iter().zip(degrees).map(|(w, d)| w * d).sum()
+}
+
+fn rev_lex(a: &[u64], b: &[u64]) -> Ordering {
+    for (x, y) in a.iter().zip(b).rev() {
+        if x != y {
+            return y.cmp(x);
+        }
+    }
+    Ordering::Equal
+}
+
+fn dot(row: &[i64], degrees: &[u64]) -> i128 {
+    row.iter()
+        .zip(degrees)
+        .map(|(m, d)| *m as i128 * *d as i128)
+        .sum()
+}
+
+/// Rank over the rationals, computed by fraction-free elimination.
+fn rank(rows: &[Vec<i64>]) -> usize {
+    let mut rows: Vec<Vec<i128>> = rows
+        .iter()
+        .map(|row| row.iter().map(|x| *x as i128).collect())
+        .collect();
+    let columns = rows.first().map_or(0, |row| row.len());
+    let mut rank = 0;
+    for j in 0..columns {
+        let pivot = match (rank..rows.len()).find(|i| rows[*i][j] != 0) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(rank, pivot);
+        let (top, bottom) = rows.split_at_mut(rank + 1);
+        let pivot_row = &top[rank];
+        for row in bottom {
+            let (p, q) = (pivot_row[j], row[j]);
+            if q == 0 {
+                continue;
+            }
+            for (x, y) in row.iter_mut().zip(pivot_row) {
+                *x = *x * p - y * q;
+            }
+            let g = row.iter().fold(0, |g, x| gcd(g, *x));
+            if g > 1 {
+                for x in row.iter_mut() {
+                    *x /= g;
+                }
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+pub(crate) fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
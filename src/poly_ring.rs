@@ -1,6 +1,6 @@
 use crate::Field;
 
-use crate::{Monomial, Polynomial};
+use crate::{Monomial, MonomialOrder, Polynomial};
 use std::fmt;
 use std::marker::PhantomData;
 use std::vec::Vec;
@@ -9,19 +9,63 @@ use std::vec::Vec;
 pub struct PolyRing<F: Field> {
     field: PhantomData<F>,
     variables: Vec<&'static str>,
+    order: MonomialOrder,
 }
 
 impl<F: Field> PolyRing<F> {
     pub fn new(variables: Vec<&'static str>) -> PolyRing<F> {
+        PolyRing::with_order(variables, MonomialOrder::Lex)
+    }
+
+    pub fn with_order(variables: Vec<&'static str>, order: MonomialOrder) -> PolyRing<F> {
+        order.validate(variables.len());
         PolyRing {
-            variables: variables,
+            variables,
+            order,
             field: PhantomData,
         }
     }
 
+    /// Builds a ring whose variables are split into blocks, each block being
+    /// strictly greater than the following ones and ordered internally by
+    /// `order`. A Gröbner basis for such a ring contains a basis of the
+    /// elimination ideal of every leading set of blocks.
+    pub fn with_blocks(blocks: Vec<Vec<&'static str>>, order: MonomialOrder) -> PolyRing<F> {
+        let order = MonomialOrder::Block(
+            blocks
+                .iter()
+                .map(|block| (block.len(), order.clone()))
+                .collect(),
+        );
+        PolyRing::with_order(blocks.into_iter().flatten().collect(), order)
+    }
+
+    pub fn order(&self) -> &MonomialOrder {
+        &self.order
+    }
+
+    /// Ring with `names` as new leading variables forming a block greater
+    /// than all the existing variables, which keep their order. Variable `i`
+    /// of `self` is variable `i + names.len()` of the result, see `embed`.
+    pub fn extend(&self, names: Vec<&'static str>) -> PolyRing<F> {
+        let order = MonomialOrder::Block(vec![
+            (names.len(), MonomialOrder::GradedRevLex),
+            (self.variable_count(), self.order.clone()),
+        ]);
+        let variables = names
+            .into_iter()
+            .chain(self.variables.iter().cloned())
+            .collect();
+        PolyRing::with_order(variables, order)
+    }
+
     pub fn variable_count(&self) -> usize {
         self.variables.len()
     }
+
+    pub fn variable_names(&self) -> &[&'static str] {
+        &self.variables
+    }
 }
 
 impl<F: Field> std::ops::Index<usize> for PolyRing<F> {
@@ -32,16 +76,29 @@ impl<F: Field> std::ops::Index<usize> for PolyRing<F> {
 }
 
 impl<F: Field + fmt::Debug + fmt::Display> PolyRing<F> {
-    pub fn monomial(&self, degrees: Vec<u64>) -> Monomial<F> {
+    pub fn monomial(&self, degrees: Vec<u64>) -> Monomial<'_, F> {
         Monomial::new(self, degrees)
     }
 
-    pub fn variables(&self) -> Vec<Polynomial<F>> {
+    /// Image in this ring of `p`, whose ring this one was obtained from with
+    /// `extend`.
+    pub fn embed(&self, p: &Polynomial<F>) -> Polynomial<'_, F> {
+        let count = p.poly_ring().variable_count();
+        assert_eq!(
+            &self.variables[self.variable_count() - count..],
+            p.poly_ring().variable_names()
+        );
+        let offset = self.variable_count() - count;
+        let map: Vec<Option<usize>> = (0..count).map(|i| Some(i + offset)).collect();
+        p.map_variables(self, &map)
+    }
+
+    pub fn variables(&self) -> Vec<Polynomial<'_, F>> {
         let mut res = Vec::new();
         for i in 0..self.variables.len() {
             let mut deg = vec![0; self.variable_count()];
             deg[i] = 1;
-            res.push(Polynomial::new(&self, vec![(F::one(), self.monomial(deg))]));
+            res.push(Polynomial::new(self, vec![(F::one(), self.monomial(deg))]));
         }
         res
     }
@@ -46,6 +46,20 @@ impl<'lt, F: Field + Debug + Display> Polynomial<'lt, F> {
         }
     }
 
+    pub fn poly_ring(&self) -> &'lt PolyRing<F> {
+        self.poly_ring
+    }
+
+    /// Terms sorted by decreasing monomial.
+    pub fn terms(&self) -> &[(F, Monomial<'lt, F>)] {
+        &self.terms
+    }
+
+    /// Total degree, the zero polynomial having degree 0.
+    pub fn degree(&self) -> u64 {
+        self.terms.iter().map(|t| t.1.degree()).max().unwrap_or(0)
+    }
+
     pub fn is_zero(&self) -> bool {
         self.terms.is_empty()
     }
@@ -69,6 +83,62 @@ impl<'lt, F: Field + Debug + Display> Polynomial<'lt, F> {
         self.terms[0].0.clone()
     }
 
+    /// Multivariate division of `self` by `divs`: returns the quotients `q`
+    /// and the remainder `r` such that `self = Σ q[i] * divs[i] + r`, where no
+    /// term of `r` is divisible by the leading monomial of a divisor. The
+    /// leading term is always divided by the first divisor that can divide
+    /// it. Zero divisors are ignored.
+    pub fn divide_by(&self, divs: &[Self]) -> (Vec<Self>, Self) {
+        let mut q = vec![Vec::<(F, Monomial<'lt, F>)>::new(); divs.len()];
+        let mut r = Vec::<(F, Monomial<'lt, F>)>::new();
+        let mut p = self.clone();
+        while !p.is_zero() {
+            let lm = p.lm();
+            match divs
+                .iter()
+                .position(|d| !d.is_zero() && lm.is_divisible(&d.lm()))
+            {
+                Some(i) => {
+                    let term = (p.lc() / divs[i].lc(), lm / &divs[i].lm());
+                    let delta = Polynomial::new(self.poly_ring, vec![term.clone()]);
+                    p = p - delta * divs[i].clone();
+                    q[i].push(term);
+                }
+                None => r.push(p.terms.remove(0)),
+            }
+        }
+        let q = q
+            .into_iter()
+            .map(|terms| Polynomial::new(self.poly_ring, terms))
+            .collect();
+        (q, Polynomial::new(self.poly_ring, r))
+    }
+
+    /// Image of `self` in `poly_ring`, variable `i` being sent to variable
+    /// `map[i]`. Variables mapped to `None` must not occur in `self`.
+    pub fn map_variables<'a>(
+        &self,
+        poly_ring: &'a PolyRing<F>,
+        map: &[Option<usize>],
+    ) -> Polynomial<'a, F> {
+        assert_eq!(map.len(), self.poly_ring.variable_count());
+        let terms = self
+            .terms
+            .iter()
+            .map(|(c, m)| {
+                let mut degrees = vec![0; poly_ring.variable_count()];
+                for (i, d) in m.degrees().iter().enumerate() {
+                    match map[i] {
+                        Some(j) => degrees[j] += d,
+                        None => assert_eq!(*d, 0, "Unmapped variable {}", &self.poly_ring[i]),
+                    }
+                }
+                (c.clone(), poly_ring.monomial(degrees))
+            })
+            .collect();
+        Polynomial::new(poly_ring, terms)
+    }
+
     pub fn s_polynomial(&self, other: &Self) -> Self {
         if self.is_zero() {
             Polynomial::new(self.poly_ring, Vec::new())
@@ -204,6 +274,9 @@ impl<'lt, F: Field + Debug + Display> Mul<Self> for Polynomial<'lt, F> {
 impl<'lt, F: Field + Debug + Display> Mul<F> for Polynomial<'lt, F> {
     type Output = Self;
     fn mul(mut self, other: F) -> Self {
+        if other.is_zero() {
+            self.terms.clear();
+        }
         for t in &mut self.terms {
             t.0 *= other.clone();
         }
@@ -214,38 +287,7 @@ impl<'lt, F: Field + Debug + Display> Mul<F> for Polynomial<'lt, F> {
 impl<'lt, F: Field + Debug + Display> Div<&Vec<Self>> for Polynomial<'lt, F> {
     type Output = Self;
     fn div(self, divs: &Vec<Self>) -> Self {
-        let mut all_zero = true;
-        for d in divs {
-            if !d.is_zero() {
-                all_zero = false;
-            }
-        }
-        assert!(!all_zero);
-
-        /*println!("Div {} with", self);
-        for d in divs {
-            println!("  {}", d);
-        }*/
-
-        let mut p = self.clone();
-        let mut r = Vec::<(F, Monomial<'lt, F>)>::new();
-        while !p.is_zero() {
-            let mut divised = false;
-            for div in divs {
-                while !p.is_zero() && p.lm().is_divisible(&div.lm()) {
-                    let delta = Polynomial::new(
-                        self.poly_ring,
-                        vec![(p.lc() / div.lc(), p.lm() / &div.lm())],
-                    );
-                    p = p - delta * div.clone();
-                    divised = true;
-                }
-            }
-            if !divised {
-                r.push(p.terms.remove(0));
-            }
-        }
-        return Polynomial::new(self.poly_ring, r);
+        self.divide_by(divs).1
     }
 }
 
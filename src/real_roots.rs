@@ -0,0 +1,140 @@
+use crate::univariate::{derivative, div_rem, from_polynomial, squarefree};
+use crate::{Field, Polynomial};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+
+/// Interval containing exactly one real root of a polynomial: the open
+/// interval from `lower` to `upper`, whose ends are not roots, or the root
+/// itself when `lower == upper`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RootInterval<F: Field> {
+    pub lower: F,
+    pub upper: F,
+}
+
+/// Disjoint intervals isolating the distinct real roots of the nonzero
+/// univariate polynomial `p`, by increasing root. They are found by bisection
+/// of a bound on the roots, counting the roots in each interval with a Sturm
+/// sequence. Computations are exact, so `F` should be an ordered field such
+/// as the rationals.
+pub fn isolate_real_roots<F: Field + Debug + Display + PartialOrd>(
+    p: &Polynomial<F>,
+) -> Vec<RootInterval<F>> {
+    let p = from_polynomial(p);
+    assert!(!p.is_empty(), "Zero polynomial has infinitely many roots");
+    let p = squarefree(&p);
+    if p.len() < 2 {
+        return Vec::new();
+    }
+    let sturm = sturm_sequence(&p);
+    let changes = |x: &F| sign_changes(&sturm, x);
+
+    // Cauchy bound: every root is smaller than 1 + max |p_i / p_d|.
+    let lc = p[p.len() - 1].clone();
+    let bound = p[..p.len() - 1]
+        .iter()
+        .map(|c| abs(c.clone() / lc.clone()))
+        .fold(F::zero(), |m, x| if x > m { x } else { m })
+        + F::one();
+
+    let mut res = Vec::new();
+    let mut todo = vec![(-bound.clone(), bound)];
+    while let Some((a, b)) = todo.pop() {
+        let count = changes(&a) - changes(&b);
+        if count == 0 {
+            continue;
+        }
+        if count == 1 {
+            res.push(RootInterval { lower: a, upper: b });
+            continue;
+        }
+        // Split away from the roots so that the ends are never roots.
+        let mut m = midpoint(&a, &b);
+        while sign(&eval(&p, &m)) == Ordering::Equal {
+            m = midpoint(&m, &b);
+        }
+        todo.push((m.clone(), b));
+        todo.push((a, m));
+    }
+    res.sort_by(|x, y| x.lower.partial_cmp(&y.lower).unwrap());
+    res
+}
+
+/// Shrinks `interval`, obtained from `isolate_real_roots(p)`, by bisection
+/// until it is no wider than `width`, which must be positive.
+pub fn refine_root<F: Field + Debug + Display + PartialOrd>(
+    p: &Polynomial<F>,
+    interval: &RootInterval<F>,
+    width: &F,
+) -> RootInterval<F> {
+    assert!(*width > F::zero(), "Width must be positive");
+    let p = squarefree(&from_polynomial(p));
+    let (mut a, mut b) = (interval.lower.clone(), interval.upper.clone());
+    if a == b {
+        return interval.clone();
+    }
+    // The root is simple, so p changes sign across it.
+    let sign_a = sign(&eval(&p, &a));
+    while b.clone() - a.clone() > *width {
+        let m = midpoint(&a, &b);
+        let s = sign(&eval(&p, &m));
+        if s == Ordering::Equal {
+            return RootInterval {
+                lower: m.clone(),
+                upper: m,
+            };
+        }
+        if s == sign_a {
+            a = m;
+        } else {
+            b = m;
+        }
+    }
+    RootInterval { lower: a, upper: b }
+}
+
+/// Sturm sequence `p, p', -rem(p, p'), ...` of the squarefree `p`.
+fn sturm_sequence<F: Field>(p: &[F]) -> Vec<Vec<F>> {
+    let mut res = vec![p.to_vec(), derivative(p)];
+    loop {
+        let n = res.len();
+        let r = div_rem(&res[n - 2], &res[n - 1]).1;
+        if r.is_empty() {
+            return res;
+        }
+        res.push(r.into_iter().map(|c| -c).collect());
+    }
+}
+
+/// Number of sign changes in the values of `sequence` at `x`, zeros being
+/// ignored.
+fn sign_changes<F: Field + PartialOrd>(sequence: &[Vec<F>], x: &F) -> i64 {
+    let signs: Vec<Ordering> = sequence
+        .iter()
+        .map(|p| sign(&eval(p, x)))
+        .filter(|s| *s != Ordering::Equal)
+        .collect();
+    signs.windows(2).filter(|w| w[0] != w[1]).count() as i64
+}
+
+fn eval<F: Field>(p: &[F], x: &F) -> F {
+    p.iter()
+        .rev()
+        .fold(F::zero(), |s, c| s * x.clone() + c.clone())
+}
+
+fn sign<F: Field + PartialOrd>(x: &F) -> Ordering {
+    x.partial_cmp(&F::zero()).unwrap()
+}
+
+fn abs<F: Field + PartialOrd>(x: F) -> F {
+    if x < F::zero() {
+        -x
+    } else {
+        x
+    }
+}
+
+fn midpoint<F: Field>(a: &F, b: &F) -> F {
+    (a.clone() + b.clone()) / (F::one() + F::one())
+}
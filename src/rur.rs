@@ -0,0 +1,169 @@
+use crate::field::from_int;
+use crate::univariate::{
+    derivative, div_rem, minimal_polynomial, minimal_polynomials, mul, mul_vector, trim,
+};
+use crate::{Error, Field, GroebnerBasis, PolyRing, Polynomial};
+use std::fmt::{Debug, Display};
+
+/// Rational univariate representation of a zero-dimensional radical ideal:
+/// the points of its variety are the
+/// `(numerators[0](t) / χ'(t), ..., numerators[n-1](t) / χ'(t))` for `t`
+/// running over the roots of the minimal polynomial `χ` of the linear form
+/// `t = Σ linear_form[i] * x[i]`. Univariate polynomials are given by their
+/// coefficients by increasing degree.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Rur<F: Field> {
+    pub linear_form: Vec<F>,
+    pub minimal_polynomial: Vec<F>,
+    pub numerators: Vec<Vec<F>>,
+}
+
+impl<F: Field + Debug + Display> Rur<F> {
+    /// Derivative of the minimal polynomial, the common denominator.
+    pub fn denominator(&self) -> Vec<F> {
+        derivative(&self.minimal_polynomial)
+    }
+
+    /// Minimal polynomial as a polynomial of `poly_ring` in the variable
+    /// `variable`, for instance to isolate its real roots.
+    pub fn minimal_polynomial_in<'a>(
+        &self,
+        poly_ring: &'a PolyRing<F>,
+        variable: usize,
+    ) -> Polynomial<'a, F> {
+        let terms = self
+            .minimal_polynomial
+            .iter()
+            .enumerate()
+            .map(|(k, c)| {
+                let mut degrees = vec![0; poly_ring.variable_count()];
+                degrees[variable] = k as u64;
+                (c.clone(), poly_ring.monomial(degrees))
+            })
+            .collect();
+        Polynomial::new(poly_ring, terms)
+    }
+
+    /// Coordinates of the point of the variety given by the root `t` of the
+    /// minimal polynomial.
+    pub fn coordinates(&self, t: &F) -> Vec<F> {
+        let eval = |p: &[F]| {
+            p.iter()
+                .rev()
+                .fold(F::zero(), |s, c| s * t.clone() + c.clone())
+        };
+        let denominator = eval(&self.denominator());
+        self.numerators
+            .iter()
+            .map(|g| eval(g) / denominator.clone())
+            .collect()
+    }
+}
+
+/// Rational univariate representation of the ideal with Gröbner basis
+/// `basis`, computed from the multiplication matrices of `k[x]/I`. The
+/// linear forms `x[0] + k * x[1] + ... + k^(n-1) * x[n-1]` are tried for
+/// `k = 0, 1, ...` until one separates the points, that is until its minimal
+/// polynomial has degree `dim k[x]/I`. Then every variable is a polynomial
+/// `h` in `t` and its numerator is `h * χ'` modulo `χ`. The field must have
+/// characteristic zero.
+pub fn rational_univariate_representation<F: Field + Debug + Display>(
+    basis: &GroebnerBasis<F>,
+) -> Result<Rur<F>, Error> {
+    let n = basis.poly_ring().variable_count();
+    let (one, matrices) = basis.unit_vector_and_matrices()?;
+    let size = one.len();
+    // The ideal is radical exactly when the minimal polynomials of the
+    // variables are squarefree.
+    for (m, s) in minimal_polynomials(&matrices, &one) {
+        if s.len() < m.len() {
+            return Err(Error::NotRadical);
+        }
+    }
+
+    let mut k = 0;
+    let (linear_form, chi, krylov) = loop {
+        let c = from_int::<F>(k);
+        let mut linear_form = vec![F::one(); n];
+        for i in 1..n {
+            linear_form[i] = linear_form[i - 1].clone() * c.clone();
+        }
+        let mut matrix = vec![vec![F::zero(); size]; size];
+        for (l, m) in linear_form.iter().zip(&matrices) {
+            for (row, m_row) in matrix.iter_mut().zip(m) {
+                for (x, y) in row.iter_mut().zip(m_row) {
+                    *x += l.clone() * y.clone();
+                }
+            }
+        }
+        let chi = minimal_polynomial(&matrix, &one);
+        if chi.len() == size + 1 {
+            // Normal forms of 1, t, ..., t^(size - 1), a basis of k[x]/I,
+            // which is zero for the unit ideal.
+            let mut krylov = if size > 0 {
+                vec![one.clone()]
+            } else {
+                Vec::new()
+            };
+            while krylov.len() < size {
+                let next = mul_vector(&matrix, &krylov[krylov.len() - 1]);
+                krylov.push(next);
+            }
+            break (linear_form, chi, krylov);
+        }
+        k += 1;
+    };
+
+    let chi_prime = derivative(&chi);
+    let numerators = solve_coordinates(&krylov, &matrices, &one)
+        .into_iter()
+        .map(|h| div_rem(&mul(&h, &chi_prime), &chi).1)
+        .collect();
+    Ok(Rur {
+        linear_form,
+        minimal_polynomial: chi,
+        numerators,
+    })
+}
+
+/// Coefficients of the normal forms of the variables, `matrix * one` for
+/// every multiplication matrix, in the basis `krylov`.
+fn solve_coordinates<F: Field>(
+    krylov: &[Vec<F>],
+    matrices: &[Vec<Vec<F>>],
+    one: &[F],
+) -> Vec<Vec<F>> {
+    let size = krylov.len();
+    // Augmented matrix [K | x_0 | ... | x_(n-1)], the columns of K being the
+    // Krylov vectors, reduced by Gauss–Jordan elimination.
+    let mut rows: Vec<Vec<F>> = (0..size)
+        .map(|r| {
+            krylov
+                .iter()
+                .map(|v| v[r].clone())
+                .chain(matrices.iter().map(|m| mul_vector(m, one)[r].clone()))
+                .collect()
+        })
+        .collect();
+    for j in 0..size {
+        let pivot = (j..size).find(|r| !rows[*r][j].is_zero()).unwrap();
+        rows.swap(j, pivot);
+        let inv = F::one() / rows[j][j].clone();
+        for x in rows[j].iter_mut() {
+            *x *= inv.clone();
+        }
+        for r in 0..size {
+            if r == j || rows[r][j].is_zero() {
+                continue;
+            }
+            let c = rows[r][j].clone();
+            let pivot_row = rows[j].clone();
+            for (x, y) in rows[r].iter_mut().zip(pivot_row) {
+                *x -= c.clone() * y;
+            }
+        }
+    }
+    (0..matrices.len())
+        .map(|i| trim(rows.iter().map(|row| row[size + i].clone()).collect()))
+        .collect()
+}
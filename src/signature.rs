@@ -0,0 +1,195 @@
+use crate::{Field, Monomial, Polynomial};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+
+/// Module term `monomial * e_index`, where `e_index` is the basis vector of
+/// the `index`-th input polynomial. Signatures are compared position over
+/// term: by index first, then by monomial.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Signature<'lt, F: Field> {
+    pub index: usize,
+    pub monomial: Monomial<'lt, F>,
+}
+
+impl<'lt, F: Field + Debug + Display> Signature<'lt, F> {
+    fn mul(&self, m: &Monomial<'lt, F>) -> Self {
+        Signature {
+            index: self.index,
+            monomial: &self.monomial * m,
+        }
+    }
+
+    pub fn is_divisible(&self, other: &Self) -> bool {
+        self.index == other.index && self.monomial.is_divisible(&other.monomial)
+    }
+}
+
+impl<'lt, F: Field> Ord for Signature<'lt, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index
+            .cmp(&other.index)
+            .then_with(|| self.monomial.cmp(&other.monomial))
+    }
+}
+
+impl<'lt, F: Field> PartialOrd for Signature<'lt, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Basis element `poly` together with its signature.
+struct Labeled<'lt, F: Field> {
+    signature: Signature<'lt, F>,
+    poly: Polynomial<'lt, F>,
+}
+
+/// S-pair of basis elements `a` and `b`, `signature` coming from `a`.
+struct SPair<'lt, F: Field> {
+    signature: Signature<'lt, F>,
+    a: usize,
+    b: usize,
+}
+
+/// Replaces `polys` by a Gröbner basis of the ideal they generate, computed
+/// incrementally with a signature-based algorithm: S-pairs whose signature is
+/// divisible by a known syzygy (syzygy criterion) or by the signature of a
+/// more recent element (rewrite criterion) are never reduced.
+///
+/// Returns the signatures found for the syzygies of `polys`, which generate
+/// the leading module of the syzygy module.
+pub fn signature_groebner<'lt, F: Field + Debug + Display>(
+    polys: &mut Vec<Polynomial<'lt, F>>,
+) -> Vec<Signature<'lt, F>> {
+    let mut basis: Vec<Labeled<F>> = Vec::new();
+    let mut syzygies: Vec<Signature<F>> = Vec::new();
+    for (index, f) in polys.iter().enumerate() {
+        let unit = f
+            .poly_ring()
+            .monomial(vec![0; f.poly_ring().variable_count()]);
+        let signature = Signature {
+            index,
+            monomial: unit,
+        };
+        // Koszul syzygies f * e_index - g * f_index of the previous basis.
+        for g in &basis {
+            add_syzygy(
+                &mut syzygies,
+                Signature {
+                    index,
+                    monomial: g.poly.lm(),
+                },
+            );
+        }
+
+        let mut pairs = Vec::new();
+        process(&mut basis, &mut syzygies, &mut pairs, signature, f.clone());
+        while let Some(pair) = pop_min(&mut pairs) {
+            if syzygies.iter().any(|s| pair.signature.is_divisible(s))
+                || basis[pair.a + 1..]
+                    .iter()
+                    .any(|g| pair.signature.is_divisible(&g.signature))
+            {
+                continue;
+            }
+            let s = basis[pair.a].poly.s_polynomial(&basis[pair.b].poly);
+            process(&mut basis, &mut syzygies, &mut pairs, pair.signature, s);
+        }
+    }
+    *polys = basis.into_iter().map(|g| g.poly).collect();
+    syzygies
+}
+
+/// Regular-reduces `p` of signature `signature` and adds the result to the
+/// basis unless it is a syzygy or singular top-reducible.
+fn process<'lt, F: Field + Debug + Display>(
+    basis: &mut Vec<Labeled<'lt, F>>,
+    syzygies: &mut Vec<Signature<'lt, F>>,
+    pairs: &mut Vec<SPair<'lt, F>>,
+    signature: Signature<'lt, F>,
+    p: Polynomial<'lt, F>,
+) {
+    let p = regular_reduce(basis, &signature, p);
+    if p.is_zero() {
+        add_syzygy(syzygies, signature);
+        return;
+    }
+    let lm = p.lm();
+    if basis.iter().any(|g| {
+        lm.is_divisible(&g.poly.lm()) && g.signature.mul(&(&lm / &g.poly.lm())) == signature
+    }) {
+        return;
+    }
+
+    let h = basis.len();
+    for (k, g) in basis.iter().enumerate() {
+        let lcm = lm.lcm(&g.poly.lm());
+        let s1 = signature.mul(&(&lcm / &lm));
+        let s2 = g.signature.mul(&(&lcm / &g.poly.lm()));
+        if s1 > s2 {
+            pairs.push(SPair {
+                signature: s1,
+                a: h,
+                b: k,
+            });
+        } else if s2 > s1 {
+            pairs.push(SPair {
+                signature: s2,
+                a: k,
+                b: h,
+            });
+        }
+    }
+    basis.push(Labeled { signature, poly: p });
+}
+
+/// Top-reduces `p` by the basis elements whose multiple has a signature
+/// smaller than `signature`.
+fn regular_reduce<'lt, F: Field + Debug + Display>(
+    basis: &[Labeled<'lt, F>],
+    signature: &Signature<'lt, F>,
+    mut p: Polynomial<'lt, F>,
+) -> Polynomial<'lt, F> {
+    'outer: while !p.is_zero() {
+        let lm = p.lm();
+        for g in basis {
+            if !lm.is_divisible(&g.poly.lm()) {
+                continue;
+            }
+            let t = &lm / &g.poly.lm();
+            if g.signature.mul(&t) < *signature {
+                let delta = Polynomial::new(p.poly_ring(), vec![(p.lc() / g.poly.lc(), t)]);
+                p = p - delta * g.poly.clone();
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    p
+}
+
+/// Adds `signature` to the syzygy signatures, keeping them minimal.
+fn add_syzygy<'lt, F: Field + Debug + Display>(
+    syzygies: &mut Vec<Signature<'lt, F>>,
+    signature: Signature<'lt, F>,
+) {
+    if syzygies.iter().any(|s| signature.is_divisible(s)) {
+        return;
+    }
+    syzygies.retain(|s| !s.is_divisible(&signature));
+    syzygies.push(signature);
+}
+
+/// Removes and returns the pair with the smallest signature.
+fn pop_min<'lt, F: Field + Debug + Display>(
+    pairs: &mut Vec<SPair<'lt, F>>,
+) -> Option<SPair<'lt, F>> {
+    let mut best: Option<usize> = None;
+    for (k, pair) in pairs.iter().enumerate() {
+        match best {
+            Some(b) if pairs[b].signature <= pair.signature => {}
+            _ => best = Some(k),
+        }
+    }
+    best.map(|k| pairs.swap_remove(k))
+}
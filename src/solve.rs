@@ -0,0 +1,207 @@
+use crate::univariate::{complex_roots, minimal_polynomials};
+use crate::{fglm, triangular_decompose, Error, Field, GroebnerBasis, Ideal, MonomialOrder};
+use crate::{PolyRing, Polynomial};
+use num_complex::Complex64;
+use num_traits::{ToPrimitive, Zero};
+use std::fmt::{Debug, Display};
+
+/// Complex solutions of the zero-dimensional system `ideal`, each given by
+/// the values of the variables of its ring. The computations are exact up to
+/// triangular sets: a grevlex basis of the radical of the ideal is converted
+/// to lex with `fglm` and split with `triangular_decompose`. Only the final
+/// back-substitution, which finds the roots of univariate polynomials, and
+/// a final Newton polishing on the input system are done in floating point.
+/// Every solution is listed once.
+pub fn solve<F: Field + Debug + Display + ToPrimitive>(
+    ideal: &Ideal<F>,
+) -> Result<Vec<Vec<Complex64>>, Error> {
+    let names = ideal.poly_ring().variable_names().to_vec();
+    let identity: Vec<Option<usize>> = (0..names.len()).map(Some).collect();
+    let grevlex = PolyRing::with_order(names.clone(), MonomialOrder::GradedRevLex);
+    let lex = PolyRing::new(names.clone());
+    let generators = ideal
+        .generators()
+        .iter()
+        .map(|g| g.map_variables(&grevlex, &identity))
+        .collect();
+    let basis = radical(&GroebnerBasis::new(&grevlex, generators))?;
+    let mut res = Vec::new();
+    for chain in triangular_decompose(&fglm(&basis, &lex)?)? {
+        res.extend(back_substitute(&chain, names.len()));
+    }
+    let system: Vec<Vec<(f64, Vec<u64>)>> = ideal
+        .generators()
+        .iter()
+        .map(|g| {
+            g.terms()
+                .iter()
+                .map(|(c, m)| (to_f64(c), m.degrees().to_vec()))
+                .collect()
+        })
+        .collect();
+    for point in &mut res {
+        polish(&system, point);
+    }
+    Ok(res)
+}
+
+fn to_f64<F: ToPrimitive>(c: &F) -> f64 {
+    c.to_f64().expect("Coefficient not representable as f64")
+}
+
+/// Value at `point` of the polynomial with terms `terms`.
+fn eval(terms: &[(f64, Vec<u64>)], point: &[Complex64]) -> Complex64 {
+    terms
+        .iter()
+        .map(|(c, degrees)| {
+            degrees
+                .iter()
+                .zip(point)
+                .fold(Complex64::new(*c, 0.0), |v, (d, x)| v * x.powu(*d as u32))
+        })
+        .sum()
+}
+
+/// Improves `point`, an approximate solution of `system`, with Gauss–Newton
+/// steps as long as they decrease the residual. Coefficients of lex bases
+/// are often too large for the back-substitution to be accurate.
+fn polish(system: &[Vec<(f64, Vec<u64>)>], point: &mut Vec<Complex64>) {
+    let n = point.len();
+    let residual =
+        |point: &[Complex64]| -> f64 { system.iter().map(|p| eval(p, point).norm_sqr()).sum() };
+    let mut current = residual(point);
+    for _ in 0..10 {
+        if current == 0.0 {
+            return;
+        }
+        // Normal equations J^H J delta = J^H f.
+        let values: Vec<Complex64> = system.iter().map(|p| eval(p, point)).collect();
+        let jacobian: Vec<Vec<Complex64>> = system
+            .iter()
+            .map(|p| (0..n).map(|i| eval(&partial(p, i), point)).collect())
+            .collect();
+        let mut a = vec![vec![Complex64::zero(); n + 1]; n];
+        for (row, f) in jacobian.iter().zip(&values) {
+            for i in 0..n {
+                for j in 0..n {
+                    a[i][j] += row[i].conj() * row[j];
+                }
+                a[i][n] += row[i].conj() * f;
+            }
+        }
+        let delta = match solve_linear(a) {
+            Some(delta) => delta,
+            None => return,
+        };
+        let next: Vec<Complex64> = point.iter().zip(&delta).map(|(x, d)| x - d).collect();
+        let next_residual = residual(&next);
+        if next_residual.is_nan() || next_residual >= current {
+            return;
+        }
+        *point = next;
+        current = next_residual;
+    }
+}
+
+/// Derivative with respect to the variable `i`.
+fn partial(terms: &[(f64, Vec<u64>)], i: usize) -> Vec<(f64, Vec<u64>)> {
+    terms
+        .iter()
+        .filter(|(_, degrees)| degrees[i] > 0)
+        .map(|(c, degrees)| {
+            let mut degrees = degrees.clone();
+            degrees[i] -= 1;
+            (c * (degrees[i] + 1) as f64, degrees)
+        })
+        .collect()
+}
+
+/// Solution of the square system with augmented matrix `a`, by Gaussian
+/// elimination with partial pivoting. `None` if the matrix is singular.
+fn solve_linear(mut a: Vec<Vec<Complex64>>) -> Option<Vec<Complex64>> {
+    let n = a.len();
+    for j in 0..n {
+        let pivot = (j..n).max_by(|x, y| a[*x][j].norm().total_cmp(&a[*y][j].norm()))?;
+        if a[pivot][j].is_zero() {
+            return None;
+        }
+        a.swap(j, pivot);
+        let (top, bottom) = a.split_at_mut(j + 1);
+        let pivot_row = &top[j];
+        for row in bottom {
+            let c = row[j] / pivot_row[j];
+            for (x, y) in row.iter_mut().zip(pivot_row).skip(j) {
+                *x -= c * y;
+            }
+        }
+    }
+    let mut res = vec![Complex64::zero(); n];
+    for j in (0..n).rev() {
+        let sum: Complex64 = (j + 1..n).map(|k| a[j][k] * res[k]).sum();
+        res[j] = (a[j][n] - sum) / a[j][j];
+    }
+    Some(res)
+}
+
+/// Radical of a zero-dimensional ideal, obtained by adding the squarefree
+/// parts of the minimal polynomials of the variables (Seidenberg's lemma).
+fn radical<'lt, F: Field + Debug + Display>(
+    basis: &GroebnerBasis<'lt, F>,
+) -> Result<GroebnerBasis<'lt, F>, Error> {
+    let poly_ring = basis.poly_ring();
+    let (one, matrices) = basis.unit_vector_and_matrices()?;
+    if one.is_empty() {
+        return Ok(basis.clone());
+    }
+    let mut generators = basis.polys().to_vec();
+    for (i, (m, s)) in minimal_polynomials(&matrices, &one).into_iter().enumerate() {
+        if s.len() < m.len() {
+            let mut terms = Vec::new();
+            for (k, c) in s.into_iter().enumerate() {
+                let mut degrees = vec![0; poly_ring.variable_count()];
+                degrees[i] = k as u64;
+                terms.push((c, poly_ring.monomial(degrees)));
+            }
+            generators.push(Polynomial::new(poly_ring, terms));
+        }
+    }
+    Ok(GroebnerBasis::new(poly_ring, generators))
+}
+
+/// Points of the variety of the triangular set `chain` of a radical ideal,
+/// given by increasing main variable.
+fn back_substitute<F: Field + Debug + Display + ToPrimitive>(
+    chain: &[Polynomial<F>],
+    variable_count: usize,
+) -> Vec<Vec<Complex64>> {
+    let mut points = vec![vec![Complex64::zero(); variable_count]];
+    for p in chain {
+        let lm = p.lm();
+        let k = lm.degrees().iter().position(|d| *d > 0).unwrap();
+        let mut next = Vec::new();
+        for point in &points {
+            // p with the smaller variables replaced by their values, as a
+            // univariate polynomial in the variable k.
+            let mut coefficients = vec![Complex64::zero(); lm.degrees()[k] as usize + 1];
+            for (c, m) in p.terms() {
+                let c = to_f64(c);
+                let value = m
+                    .degrees()
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != k)
+                    .fold(Complex64::new(c, 0.0), |v, (j, d)| {
+                        v * point[j].powu(*d as u32)
+                    });
+                coefficients[m.degrees()[k] as usize] += value;
+            }
+            for z in complex_roots(&coefficients) {
+                let mut point = point.clone();
+                point[k] = z;
+                next.push(point);
+            }
+        }
+        points = next;
+    }
+    points
+}
@@ -0,0 +1,79 @@
+use crate::{Error, Field, GroebnerBasis, Ideal, Polynomial};
+use std::fmt::{Debug, Display};
+
+/// Splits the variety of the zero-dimensional ideal with lexicographic
+/// Gröbner basis `basis` into triangular sets, in the spirit of Lazard's
+/// algorithm. Each component is the reduced lex basis of an ideal with one
+/// polynomial per variable, the `k`-th one involving only the `k` smallest
+/// variables and having a pure power of the greatest one as leading
+/// monomial. Components are ordered by increasing main variable, and the
+/// union of their varieties is the variety of `basis`.
+///
+/// Fails with `Error::NotLex` if the order of `basis` does not compare
+/// monomials like `Lex`, and with `Error::NotZeroDimensional` if the ideal
+/// is not zero-dimensional.
+pub fn triangular_decompose<'lt, F: Field + Debug + Display>(
+    basis: &GroebnerBasis<'lt, F>,
+) -> Result<Vec<Vec<Polynomial<'lt, F>>>, Error> {
+    if !basis.order().is_lex(basis.poly_ring().variable_count()) {
+        return Err(Error::NotLex);
+    }
+    if !basis.is_zero_dimensional() {
+        return Err(Error::NotZeroDimensional);
+    }
+    let poly_ring = basis.poly_ring();
+    let n = poly_ring.variable_count();
+    let mut res: Vec<GroebnerBasis<F>> = Vec::new();
+    let mut todo = vec![basis.clone()];
+    while let Some(g) = todo.pop() {
+        if g.iter().any(|p| p.is_one()) || res.contains(&g) {
+            continue;
+        }
+        if g.len() == n {
+            res.push(g);
+            continue;
+        }
+        // Elements are sorted by increasing leading monomial, hence by
+        // increasing main variable. The first main variable with several
+        // elements gives a splitting polynomial: the leading coefficient of
+        // the smallest of them is a zero divisor modulo the ideal.
+        let main = g.iter().map(|p| main_variable(p)).collect::<Vec<_>>();
+        let k = (1..g.len()).find(|k| main[*k] == main[k - 1]).unwrap() - 1;
+        let h = initial(&g.polys()[k], main[k]);
+        let ideal = Ideal::new(poly_ring, g.polys().to_vec());
+        let principal = Ideal::new(poly_ring, vec![h]);
+        todo.push(ideal.sum(&principal).groebner_basis());
+        todo.push(ideal.saturation(&principal).groebner_basis());
+    }
+    Ok(res.into_iter().map(|g| g.polys().to_vec()).collect())
+}
+
+/// Index of the greatest variable of `p`, the first one in its leading
+/// monomial for the lex order.
+fn main_variable<F: Field + Debug + Display>(p: &Polynomial<F>) -> usize {
+    let lm = p.lm();
+    lm.degrees()
+        .iter()
+        .position(|d| *d > 0)
+        .unwrap_or_else(|| lm.variable_count())
+}
+
+/// Coefficient of the highest power of the variable `k` in `p`, which does
+/// not involve the greater variables.
+fn initial<'lt, F: Field + Debug + Display>(
+    p: &Polynomial<'lt, F>,
+    k: usize,
+) -> Polynomial<'lt, F> {
+    let d = p.lm().degrees()[k];
+    let terms = p
+        .terms()
+        .iter()
+        .filter(|t| t.1.degrees()[k] == d)
+        .map(|(c, m)| {
+            let mut degrees = m.degrees().to_vec();
+            degrees[k] = 0;
+            (c.clone(), p.poly_ring().monomial(degrees))
+        })
+        .collect();
+    Polynomial::new(p.poly_ring(), terms)
+}
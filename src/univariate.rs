@@ -0,0 +1,258 @@
+use crate::field::from_int;
+use crate::{Field, Polynomial};
+use num_complex::Complex64;
+use num_traits::Zero;
+use std::fmt::{Debug, Display};
+
+/// Removes the zero coefficients of highest degree. Univariate polynomials
+/// are given by their coefficients by increasing degree.
+pub(crate) fn trim<F: Field>(mut p: Vec<F>) -> Vec<F> {
+    while p.last().is_some_and(|c| c.is_zero()) {
+        p.pop();
+    }
+    p
+}
+
+/// Coefficients of `p`, which must involve at most one variable.
+pub(crate) fn from_polynomial<F: Field + Debug + Display>(p: &Polynomial<F>) -> Vec<F> {
+    let variable = p
+        .terms()
+        .iter()
+        .flat_map(|t| t.1.degrees().iter().position(|d| *d > 0))
+        .next();
+    let mut res = vec![F::zero(); p.degree() as usize + 1];
+    for (c, m) in p.terms() {
+        let degrees = m.degrees();
+        assert!(
+            degrees
+                .iter()
+                .enumerate()
+                .all(|(i, d)| *d == 0 || Some(i) == variable),
+            "Polynomial must be univariate"
+        );
+        res[m.degree() as usize] = c.clone();
+    }
+    trim(res)
+}
+
+pub(crate) fn derivative<F: Field>(p: &[F]) -> Vec<F> {
+    let res = p
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, c)| c.clone() * from_int::<F>(k as i64))
+        .collect();
+    trim(res)
+}
+
+pub(crate) fn mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut res = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            res[i + j] += x.clone() * y.clone();
+        }
+    }
+    trim(res)
+}
+
+/// Quotient and remainder of the division of `a` by the nonzero `b`.
+pub(crate) fn div_rem<F: Field>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+    assert!(!b.is_empty(), "Division by zero");
+    let mut r = trim(a.to_vec());
+    if r.len() < b.len() {
+        return (Vec::new(), r);
+    }
+    let mut q = vec![F::zero(); r.len() - b.len() + 1];
+    let lc = b[b.len() - 1].clone();
+    while r.len() >= b.len() {
+        let shift = r.len() - b.len();
+        let c = r[r.len() - 1].clone() / lc.clone();
+        for (k, x) in b.iter().enumerate() {
+            r[shift + k] -= c.clone() * x.clone();
+        }
+        r.pop();
+        q[shift] = c;
+        r = trim(r);
+    }
+    (q, r)
+}
+
+/// Monic greatest common divisor.
+pub(crate) fn gcd<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let (mut a, mut b) = (trim(a.to_vec()), trim(b.to_vec()));
+    while !b.is_empty() {
+        let r = div_rem(&a, &b).1;
+        a = b;
+        b = r;
+    }
+    monic(a)
+}
+
+pub(crate) fn monic<F: Field>(p: Vec<F>) -> Vec<F> {
+    match p.last() {
+        Some(lc) => {
+            let inv = F::one() / lc.clone();
+            p.into_iter().map(|c| c * inv.clone()).collect()
+        }
+        None => p,
+    }
+}
+
+/// Product of the distinct irreducible factors of `p`, made monic. Only
+/// valid in characteristic zero.
+pub(crate) fn squarefree<F: Field>(p: &[F]) -> Vec<F> {
+    let g = gcd(p, &derivative(p));
+    monic(div_rem(p, &g).0)
+}
+
+/// Echelon form of linearly independent vectors inserted one at a time:
+/// row `k` has its first nonzero entry at column `pivots[k]` and is the
+/// combination `combinations[k]` of the inserted vectors.
+pub(crate) struct Echelon<F: Field> {
+    rows: Vec<Vec<F>>,
+    pivots: Vec<usize>,
+    combinations: Vec<Vec<F>>,
+}
+
+impl<F: Field> Echelon<F> {
+    pub(crate) fn new() -> Self {
+        Echelon {
+            rows: Vec::new(),
+            pivots: Vec::new(),
+            combinations: Vec::new(),
+        }
+    }
+
+    /// Coefficients `c` such that `v = Σ c[k] * vectors[k]` if `v` depends
+    /// on the inserted `vectors`, otherwise `None` after inserting `v`.
+    pub(crate) fn insert(&mut self, v: &[F]) -> Option<Vec<F>> {
+        let mut row = v.to_vec();
+        let mut combination = vec![F::zero(); self.rows.len()];
+        for (k, pivot) in self.pivots.iter().enumerate() {
+            if row[*pivot].is_zero() {
+                continue;
+            }
+            let c = row[*pivot].clone() / self.rows[k][*pivot].clone();
+            for (x, y) in row.iter_mut().zip(self.rows[k].iter()) {
+                *x -= c.clone() * y.clone();
+            }
+            for (x, y) in combination.iter_mut().zip(self.combinations[k].iter()) {
+                *x += c.clone() * y.clone();
+            }
+        }
+        match row.iter().position(|x| !x.is_zero()) {
+            None => Some(combination),
+            Some(pivot) => {
+                // row = v - Σ combination[k] * vectors[k]
+                let mut combination: Vec<F> = combination.into_iter().map(|c| -c).collect();
+                combination.push(F::one());
+                self.rows.push(row);
+                self.pivots.push(pivot);
+                self.combinations.push(combination);
+                None
+            }
+        }
+    }
+}
+
+/// Monic polynomial `m` of least degree such that `m(matrix) * v = 0`.
+pub(crate) fn minimal_polynomial<F: Field>(matrix: &[Vec<F>], v: &[F]) -> Vec<F> {
+    // The first Krylov vector matrix^k * v depending on the previous ones.
+    let mut echelon = Echelon::new();
+    let mut current = v.to_vec();
+    loop {
+        if let Some(combination) = echelon.insert(&current) {
+            let mut res: Vec<F> = combination.into_iter().map(|c| -c).collect();
+            res.push(F::one());
+            return res;
+        }
+        current = mul_vector(matrix, &current);
+    }
+}
+
+/// Minimal polynomials `m` of the `matrices` for `v`, as given by
+/// `minimal_polynomial`, each with its squarefree part.
+pub(crate) fn minimal_polynomials<F: Field>(
+    matrices: &[Vec<Vec<F>>],
+    v: &[F],
+) -> Vec<(Vec<F>, Vec<F>)> {
+    matrices
+        .iter()
+        .map(|matrix| {
+            let m = minimal_polynomial(matrix, v);
+            let s = squarefree(&m);
+            (m, s)
+        })
+        .collect()
+}
+
+/// Matrix times column vector.
+pub(crate) fn mul_vector<F: Field>(matrix: &[Vec<F>], v: &[F]) -> Vec<F> {
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(v.iter())
+                .fold(F::zero(), |s, (a, b)| s + a.clone() * b.clone())
+        })
+        .collect()
+}
+
+/// All the complex roots of `p`, repeated according to multiplicity,
+/// computed with the Aberth–Ehrlich simultaneous iteration.
+pub(crate) fn complex_roots(p: &[Complex64]) -> Vec<Complex64> {
+    let mut p = p.to_vec();
+    while p.last().is_some_and(|c| c.is_zero()) {
+        p.pop();
+    }
+    if p.len() < 2 {
+        return Vec::new();
+    }
+    let lc = p[p.len() - 1];
+    let p: Vec<Complex64> = p.iter().map(|c| c / lc).collect();
+    let d = p.len() - 1;
+    let dp: Vec<Complex64> = (1..=d).map(|k| p[k] * k as f64).collect();
+
+    // Starting points on a circle containing all the roots.
+    let radius = 1.0 + p[..d].iter().map(|c| c.norm()).fold(0.0, f64::max);
+    let mut z: Vec<Complex64> = (0..d)
+        .map(|k| {
+            Complex64::from_polar(
+                radius,
+                (2.0 * k as f64 + 0.5) * std::f64::consts::PI / d as f64,
+            )
+        })
+        .collect();
+    for _ in 0..1000 {
+        let mut converged = true;
+        for k in 0..d {
+            let value = horner(&p, z[k]);
+            if value.is_zero() {
+                continue;
+            }
+            let ratio = value / horner(&dp, z[k]);
+            let sum: Complex64 = (0..d)
+                .filter(|j| *j != k)
+                .map(|j| (z[k] - z[j]).inv())
+                .sum();
+            let step = ratio / (Complex64::new(1.0, 0.0) - ratio * sum);
+            if step.is_finite() {
+                z[k] -= step;
+                if step.norm() > 4.0 * f64::EPSILON * (1.0 + z[k].norm()) {
+                    converged = false;
+                }
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+    z
+}
+
+fn horner(p: &[Complex64], z: Complex64) -> Complex64 {
+    p.iter().rev().fold(Complex64::zero(), |s, c| s * z + c)
+}
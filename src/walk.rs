@@ -0,0 +1,166 @@
+use crate::buchberger::reduce_basis;
+use crate::monomial_order::gcd;
+use crate::{Field, GroebnerBasis, MonomialOrder, PolyRing, Polynomial};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+
+/// Terms of a polynomial as coefficients and exponent vectors, so that
+/// polynomials can outlive the rings of the intermediate orders.
+type Terms<F> = Vec<(F, Vec<u64>)>;
+
+/// Reduced Gröbner basis for the order of `target` of the ideal with Gröbner
+/// basis `basis`, computed with the Gröbner walk. The weight vector refined
+/// by the order of `basis` is moved in a straight line to the one refined by
+/// the target order. At each crossing of a cone of the Gröbner fan, the basis
+/// is converted by computing a Gröbner basis of the initial forms only and
+/// lifting it back to the ideal. Unlike `fglm`, the ideal can have any
+/// dimension. `target` must have the same variables as the ring of `basis`.
+pub fn groebner_walk<'b, F: Field + Debug + Display>(
+    basis: &GroebnerBasis<F>,
+    target: &'b PolyRing<F>,
+) -> GroebnerBasis<'b, F> {
+    let names = target.variable_names().to_vec();
+    assert_eq!(basis.poly_ring().variable_names(), &names[..]);
+    let n = names.len();
+    let tau = target.order().weight(n);
+    let mut weight = basis.order().weight(n);
+    let mut order = basis.order().clone();
+    let mut polys: Vec<Terms<F>> = basis.iter().map(|p| to_terms(p)).collect();
+    // The order of basis refines its weight, so the first conversion is to
+    // this weight refined by the target order.
+    loop {
+        let next = MonomialOrder::weighted(weight.clone(), target.order().clone());
+        polys = convert(&names, &order, &next, &weight, &polys);
+        order = next;
+        match next_weight(&polys, &weight, &tau, target.order()) {
+            Some(w) => weight = w,
+            None => break,
+        }
+    }
+    // The leading monomials are now those for the target order.
+    let polys = polys.iter().map(|p| from_terms(target, p)).collect();
+    GroebnerBasis::from_reduced(target, polys)
+}
+
+/// Converts `polys`, a reduced Gröbner basis for `old`, into one for `new`,
+/// both orders refining `weight` in the closure of the Gröbner cone of `old`.
+fn convert<F: Field + Debug + Display>(
+    names: &[&'static str],
+    old: &MonomialOrder,
+    new: &MonomialOrder,
+    weight: &[u64],
+    polys: &[Terms<F>],
+) -> Vec<Terms<F>> {
+    let old = PolyRing::with_order(names.to_vec(), old.clone());
+    let new = PolyRing::with_order(names.to_vec(), new.clone());
+    let identity: Vec<Option<usize>> = (0..names.len()).map(Some).collect();
+    let polys: Vec<Polynomial<F>> = polys.iter().map(|p| from_terms(&old, p)).collect();
+    let initial: Vec<Polynomial<F>> = polys.iter().map(|p| initial_form(p, weight)).collect();
+
+    // The initial forms are a Gröbner basis of the initial ideal for the old
+    // order. A basis for the new order is lifted back through the division
+    // by the initial forms.
+    let generators = initial
+        .iter()
+        .map(|p| p.map_variables(&new, &identity))
+        .collect();
+    let mut lifted: Vec<Polynomial<F>> = GroebnerBasis::new(&new, generators)
+        .iter()
+        .map(|h| {
+            let (q, r) = h.map_variables(&old, &identity).divide_by(&initial);
+            debug_assert!(r.is_zero());
+            let f = q
+                .into_iter()
+                .zip(polys.iter())
+                .fold(Polynomial::new(&old, Vec::new()), |f, (q, g)| {
+                    f + q * g.clone()
+                });
+            f.map_variables(&new, &identity)
+        })
+        .collect();
+    reduce_basis(&mut lifted);
+    lifted.iter().map(|p| to_terms(p)).collect()
+}
+
+/// First weight on the segment from `weight` to `tau` where a leading
+/// monomial of `polys` changes, or `None` if they are the leading monomials
+/// for `target`.
+fn next_weight<F: Field>(
+    polys: &[Terms<F>],
+    weight: &[u64],
+    tau: &[u64],
+    target: &MonomialOrder,
+) -> Option<Vec<u64>> {
+    // The crossing is at weight + t * (tau - weight) with t = a / b.
+    let mut best: Option<(i128, i128)> = None;
+    for p in polys {
+        let lead = &p[0].1;
+        for (_, e) in &p[1..] {
+            if target.compare(lead, e) != Ordering::Less {
+                continue;
+            }
+            let a = dot(weight, lead) - dot(weight, e);
+            let c = dot(tau, lead) - dot(tau, e);
+            let t = if c < 0 { (a, a - c) } else { (1, 1) };
+            match best {
+                Some((x, y)) if x * t.1 <= t.0 * y => {}
+                _ => best = Some(t),
+            }
+        }
+    }
+    best.map(|(a, b)| {
+        let w: Vec<i128> = weight
+            .iter()
+            .zip(tau)
+            .map(|(w, t)| (b - a) * *w as i128 + a * *t as i128)
+            .collect();
+        let g = w.iter().fold(0, |g, x| gcd(g, *x)).max(1);
+        w.iter().map(|x| (x / g) as u64).collect()
+    })
+}
+
+/// Sum of the terms of `p` of maximal weighted degree.
+fn initial_form<'lt, F: Field + Debug + Display>(
+    p: &Polynomial<'lt, F>,
+    weight: &[u64],
+) -> Polynomial<'lt, F> {
+    let max = p
+        .terms()
+        .iter()
+        .map(|t| dot(weight, t.1.degrees()))
+        .max()
+        .unwrap_or(0);
+    let terms = p
+        .terms()
+        .iter()
+        .filter(|t| dot(weight, t.1.degrees()) == max)
+        .cloned()
+        .collect();
+    Polynomial::new(p.poly_ring(), terms)
+}
+
+fn dot(weight: &[u64], degrees: &[u64]) -> i128 {
+    weight
+        .iter()
+        .zip(degrees)
+        .map(|(w, d)| *w as i128 * *d as i128)
+        .sum()
+}
+
+fn to_terms<F: Field + Debug + Display>(p: &Polynomial<F>) -> Terms<F> {
+    p.terms()
+        .iter()
+        .map(|(c, m)| (c.clone(), m.degrees().to_vec()))
+        .collect()
+}
+
+fn from_terms<'lt, F: Field + Debug + Display>(
+    poly_ring: &'lt PolyRing<F>,
+    terms: &[(F, Vec<u64>)],
+) -> Polynomial<'lt, F> {
+    let terms = terms
+        .iter()
+        .map(|(c, d)| (c.clone(), poly_ring.monomial(d.clone())))
+        .collect();
+    Polynomial::new(poly_ring, terms)
+}
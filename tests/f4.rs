@@ -0,0 +1,29 @@
+use buchberger::{f4, reduced_groebner, PolyRing};
+use num::BigRational;
+
+fn q(x: i64) -> BigRational {
+    BigRational::from_integer(x.into())
+}
+
+#[test]
+fn f4_matches_buchberger_for_lex() {
+    let poly_ring = PolyRing::<BigRational>::new(vec!["x", "y", "z"]);
+    let v = poly_ring.variables();
+    let (x, y, z) = (v[0].clone(), v[1].clone(), v[2].clone());
+
+    let g1 = x.clone() * x.clone() * y.clone() * y.clone() * q(3) + y.clone() * q(2);
+    let g2 = x.clone() * y.clone() * y.clone() * q(2) + x.clone() * z.clone() * z.clone()
+        - y.clone() * y.clone() * z.clone() * q(3);
+    let g3 = x.clone() * x.clone() * y.clone() * y.clone() * z.clone() * q(-2)
+        - x.clone() * y.clone() * z.clone() * z.clone() * q(3)
+        + z.clone() * q(3);
+    let g4 = x.clone() * y.clone() * y.clone() * z.clone() * q(-3) + x.clone() - y.clone();
+
+    let mut expected = vec![g1, g2, g3, g4];
+    let mut basis = expected.clone();
+    reduced_groebner(&mut expected);
+    f4(&mut basis);
+    reduced_groebner(&mut basis);
+    assert_eq!(basis, expected);
+    assert_eq!(basis, vec![z, y, x]);
+}